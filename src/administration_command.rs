@@ -0,0 +1,1276 @@
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while1},
+    character::complete::{digit1, multispace0, multispace1},
+    combinator::{map, verify},
+    error::ErrorKind,
+    multi::separated_list1,
+    sequence::{delimited, preceded, tuple},
+    IResult,
+};
+
+use crate::{
+    expression::Expression,
+    literal::{map::Map, string},
+    r#return::Return,
+    shared::{
+        if_exists_str, if_not_exists_str, indent, keyword, optional, parse_if_exists,
+        parse_if_not_exists, parse_keyword_of, parse_names, MAX_LINE_WIDTH,
+    },
+    subclause::{r#where::Where, r#yield::Yield},
+    token,
+};
+
+const SHOW_MODIFIERS: [&str; 12] = [
+    "ALL",
+    "RANGE",
+    "TEXT",
+    "POINT",
+    "FULLTEXT",
+    "VECTOR",
+    "LOOKUP",
+    "UNIQUE",
+    "KEY",
+    "EXISTENCE",
+    "DEFAULT",
+    "HOME",
+];
+
+fn is_word_of(words: &[&str], word: &str) -> bool {
+    words.iter().any(|w| w.eq_ignore_ascii_case(word))
+}
+
+fn parse_star_or_names(input: &str) -> IResult<&str, Vec<&str>> {
+    alt((map(tag("*"), |star| vec![star]), parse_names))(input)
+}
+
+fn parse_database_name(input: &str) -> IResult<&str, &str> {
+    take_while1(|c: char| c.is_alphanumeric() || "._-".contains(c))(input)
+}
+
+fn parse_or_replace(input: &str) -> IResult<&str, bool> {
+    map(
+        optional(tuple((
+            keyword("OR"),
+            multispace1,
+            keyword("REPLACE"),
+            multispace1,
+        ))),
+        |or_replace| or_replace.is_some(),
+    )(input)
+}
+
+fn or_replace_str(or_replace: bool) -> &'static str {
+    if or_replace {
+        return "OR REPLACE ";
+    }
+    ""
+}
+
+// Keeps a command on one line while it fits, otherwise puts each trailing
+// part on its own indented line.
+fn join_parts(head: String, parts: Vec<String>) -> String {
+    let line = std::iter::once(head.clone())
+        .chain(parts.iter().cloned())
+        .collect::<Vec<_>>()
+        .join(" ");
+    if line.len() <= MAX_LINE_WIDTH {
+        return line;
+    }
+    std::iter::once(head)
+        .chain(parts.iter().map(|p| indent(p)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ShowObject<'a> {
+    Indexes,
+    Constraints,
+    Procedures,
+    Functions,
+    Databases(Option<&'a str>),
+    Users,
+    Roles,
+    Privileges,
+}
+
+impl<'a> ShowObject<'a> {
+    pub fn format(&self) -> String {
+        match self {
+            ShowObject::Indexes => "INDEXES".to_string(),
+            ShowObject::Constraints => "CONSTRAINTS".to_string(),
+            ShowObject::Procedures => "PROCEDURES".to_string(),
+            ShowObject::Functions => "FUNCTIONS".to_string(),
+            ShowObject::Databases(None) => "DATABASES".to_string(),
+            ShowObject::Databases(Some(name)) => format!("DATABASE {}", name),
+            ShowObject::Users => "USERS".to_string(),
+            ShowObject::Roles => "ROLES".to_string(),
+            ShowObject::Privileges => "PRIVILEGES".to_string(),
+        }
+    }
+
+    fn parse_database(input: &'a str) -> IResult<&str, Self> {
+        alt((
+            map(keyword("DATABASES"), |_| ShowObject::Databases(None)),
+            map(
+                preceded(
+                    keyword("DATABASE"),
+                    optional(preceded(
+                        multispace1,
+                        verify(parse_database_name, |name: &str| {
+                            !is_word_of(&["YIELD", "WHERE"], name)
+                        }),
+                    )),
+                ),
+                ShowObject::Databases,
+            ),
+        ))(input)
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        alt((
+            map(alt((keyword("INDEXES"), keyword("INDEX"))), |_| {
+                ShowObject::Indexes
+            }),
+            map(alt((keyword("CONSTRAINTS"), keyword("CONSTRAINT"))), |_| {
+                ShowObject::Constraints
+            }),
+            map(alt((keyword("PROCEDURES"), keyword("PROCEDURE"))), |_| {
+                ShowObject::Procedures
+            }),
+            map(alt((keyword("FUNCTIONS"), keyword("FUNCTION"))), |_| {
+                ShowObject::Functions
+            }),
+            Self::parse_database,
+            map(alt((keyword("USERS"), keyword("USER"))), |_| {
+                ShowObject::Users
+            }),
+            map(alt((keyword("ROLES"), keyword("ROLE"))), |_| {
+                ShowObject::Roles
+            }),
+            map(alt((keyword("PRIVILEGES"), keyword("PRIVILEGE"))), |_| {
+                ShowObject::Privileges
+            }),
+        ))(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ShowCommand<'a> {
+    modifier: Option<&'a str>,
+    object: ShowObject<'a>,
+    r#yield: Option<Yield<'a>>,
+    r#where: Option<Where<'a>>,
+    r#return: Option<Return<'a>>,
+}
+
+impl<'a> ShowCommand<'a> {
+    fn modifier_str(&self) -> String {
+        self.modifier
+            .map(|m| format!("{} ", m.to_uppercase()))
+            .unwrap_or_default()
+    }
+
+    pub fn format(&self) -> String {
+        let mut lines = vec![format!(
+            "SHOW {}{}{}",
+            self.modifier_str(),
+            self.object.format(),
+            self.r#yield
+                .as_ref()
+                .map(|y| format!(" {}", y.format()))
+                .unwrap_or_default()
+        )];
+        lines.extend(self.r#where.iter().map(|w| w.format()));
+        lines.extend(self.r#return.iter().map(|r| r.format()));
+        lines.join("\n")
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                keyword("SHOW"),
+                multispace1,
+                optional(tuple((
+                    verify(token::parse, |word: &str| is_word_of(&SHOW_MODIFIERS, word)),
+                    multispace1,
+                ))),
+                ShowObject::parse,
+                optional(preceded(multispace1, Yield::parse)),
+                optional(preceded(multispace0, Where::parse)),
+                optional(preceded(multispace0, Return::parse)),
+            )),
+            |(_, _, modifier, object, r#yield, r#where, r#return)| ShowCommand {
+                modifier: modifier.map(|(modifier, _)| modifier),
+                object,
+                r#yield,
+                r#where,
+                r#return,
+            },
+        )(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Wait<'a> {
+    Wait(Option<&'a str>),
+    NoWait,
+}
+
+impl<'a> Wait<'a> {
+    pub fn format(&self) -> String {
+        match self {
+            Wait::Wait(Some(seconds)) => format!("WAIT {} SECONDS", seconds),
+            Wait::Wait(None) => "WAIT".to_string(),
+            Wait::NoWait => "NOWAIT".to_string(),
+        }
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        alt((
+            map(keyword("NOWAIT"), |_| Wait::NoWait),
+            map(
+                preceded(
+                    keyword("WAIT"),
+                    optional(preceded(
+                        multispace1,
+                        tuple((
+                            digit1,
+                            optional(preceded(
+                                multispace1,
+                                alt((keyword("SECONDS"), keyword("SECOND"), keyword("SEC"))),
+                            )),
+                        )),
+                    )),
+                ),
+                |seconds| Wait::Wait(seconds.map(|(seconds, _)| seconds)),
+            ),
+        ))(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CreateDatabase<'a> {
+    or_replace: bool,
+    name: &'a str,
+    if_not_exists: bool,
+    options: Option<Map<'a>>,
+    wait: Option<Wait<'a>>,
+}
+
+impl<'a> CreateDatabase<'a> {
+    pub fn format(&self) -> String {
+        let mut parts = vec![];
+        if let Some(options) = &self.options {
+            parts.push(format!("OPTIONS {}", options.format()));
+        }
+        parts.extend(self.wait.iter().map(|w| w.format()));
+        join_parts(
+            format!(
+                "CREATE {}DATABASE {}{}",
+                or_replace_str(self.or_replace),
+                self.name,
+                if_not_exists_str(self.if_not_exists)
+            ),
+            parts,
+        )
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                keyword("CREATE"),
+                multispace1,
+                parse_or_replace,
+                keyword("DATABASE"),
+                multispace1,
+                parse_database_name,
+                parse_if_not_exists,
+                optional(preceded(
+                    tuple((multispace1, keyword("OPTIONS"), multispace0)),
+                    Map::parse,
+                )),
+                optional(preceded(multispace1, Wait::parse)),
+            )),
+            |(_, _, or_replace, _, _, name, if_not_exists, options, wait)| CreateDatabase {
+                or_replace,
+                name,
+                if_not_exists,
+                options,
+                wait,
+            },
+        )(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DropData {
+    Dump,
+    Destroy,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DropDatabase<'a> {
+    name: &'a str,
+    if_exists: bool,
+    data: Option<DropData>,
+    wait: Option<Wait<'a>>,
+}
+
+impl<'a> DropDatabase<'a> {
+    pub fn format(&self) -> String {
+        let mut parts = vec![];
+        match self.data {
+            Some(DropData::Dump) => parts.push("DUMP DATA".to_string()),
+            Some(DropData::Destroy) => parts.push("DESTROY DATA".to_string()),
+            None => {}
+        }
+        parts.extend(self.wait.iter().map(|w| w.format()));
+        join_parts(
+            format!(
+                "DROP DATABASE {}{}",
+                self.name,
+                if_exists_str(self.if_exists)
+            ),
+            parts,
+        )
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                keyword("DROP"),
+                multispace1,
+                keyword("DATABASE"),
+                multispace1,
+                parse_database_name,
+                parse_if_exists,
+                optional(preceded(
+                    multispace1,
+                    tuple((
+                        alt((
+                            map(keyword("DUMP"), |_| DropData::Dump),
+                            map(keyword("DESTROY"), |_| DropData::Destroy),
+                        )),
+                        multispace1,
+                        keyword("DATA"),
+                    )),
+                )),
+                optional(preceded(multispace1, Wait::parse)),
+            )),
+            |(_, _, _, _, name, if_exists, data, wait)| DropDatabase {
+                name,
+                if_exists,
+                data: data.map(|(data, _, _)| data),
+                wait,
+            },
+        )(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum AlterDatabaseChange<'a> {
+    Access {
+        read_only: bool,
+    },
+    Topology {
+        primaries: &'a str,
+        secondaries: Option<&'a str>,
+    },
+}
+
+impl<'a> AlterDatabaseChange<'a> {
+    pub fn format(&self) -> String {
+        match self {
+            AlterDatabaseChange::Access { read_only: true } => "SET ACCESS READ ONLY".to_string(),
+            AlterDatabaseChange::Access { read_only: false } => "SET ACCESS READ WRITE".to_string(),
+            AlterDatabaseChange::Topology {
+                primaries,
+                secondaries,
+            } => format!(
+                "SET TOPOLOGY {} PRIMARIES{}",
+                primaries,
+                secondaries
+                    .map(|s| format!(" {} SECONDARIES", s))
+                    .unwrap_or_default()
+            ),
+        }
+    }
+
+    fn parse_access(input: &'a str) -> IResult<&str, Self> {
+        map(
+            preceded(
+                tuple((keyword("ACCESS"), multispace1, keyword("READ"), multispace1)),
+                alt((
+                    map(keyword("ONLY"), |_| true),
+                    map(keyword("WRITE"), |_| false),
+                )),
+            ),
+            |read_only| AlterDatabaseChange::Access { read_only },
+        )(input)
+    }
+
+    fn parse_topology(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                keyword("TOPOLOGY"),
+                multispace1,
+                digit1,
+                multispace1,
+                alt((keyword("PRIMARIES"), keyword("PRIMARY"))),
+                optional(preceded(
+                    multispace1,
+                    tuple((
+                        digit1,
+                        multispace1,
+                        alt((keyword("SECONDARIES"), keyword("SECONDARY"))),
+                    )),
+                )),
+            )),
+            |(_, _, primaries, _, _, secondaries)| AlterDatabaseChange::Topology {
+                primaries,
+                secondaries: secondaries.map(|(secondaries, _, _)| secondaries),
+            },
+        )(input)
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        preceded(
+            tuple((keyword("SET"), multispace1)),
+            alt((Self::parse_access, Self::parse_topology)),
+        )(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct AlterDatabase<'a> {
+    name: &'a str,
+    if_exists: bool,
+    change: AlterDatabaseChange<'a>,
+    wait: Option<Wait<'a>>,
+}
+
+impl<'a> AlterDatabase<'a> {
+    pub fn format(&self) -> String {
+        join_parts(
+            format!(
+                "ALTER DATABASE {}{}",
+                self.name,
+                if_exists_str(self.if_exists)
+            ),
+            std::iter::once(self.change.format())
+                .chain(self.wait.iter().map(|w| w.format()))
+                .collect(),
+        )
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                keyword("ALTER"),
+                multispace1,
+                keyword("DATABASE"),
+                multispace1,
+                parse_database_name,
+                parse_if_exists,
+                multispace1,
+                AlterDatabaseChange::parse,
+                optional(preceded(multispace1, Wait::parse)),
+            )),
+            |(_, _, _, _, name, if_exists, _, change, wait)| AlterDatabase {
+                name,
+                if_exists,
+                change,
+                wait,
+            },
+        )(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CreateUser<'a> {
+    or_replace: bool,
+    name: &'a str,
+    if_not_exists: bool,
+    encrypted: Option<bool>,
+    password: Expression<'a>,
+    change_required: Option<bool>,
+    status: Option<&'a str>,
+    home_database: Option<&'a str>,
+}
+
+impl<'a> CreateUser<'a> {
+    fn password_str(&self) -> String {
+        let encryption = match self.encrypted {
+            Some(true) => "ENCRYPTED ",
+            Some(false) => "PLAINTEXT ",
+            None => "",
+        };
+        let change = match self.change_required {
+            Some(true) => " CHANGE REQUIRED",
+            Some(false) => " CHANGE NOT REQUIRED",
+            None => "",
+        };
+        format!(
+            "SET {}PASSWORD {}{}",
+            encryption,
+            self.password.format(),
+            change
+        )
+    }
+
+    pub fn format(&self) -> String {
+        let mut parts = vec![self.password_str()];
+        parts.extend(
+            self.status
+                .map(|s| format!("SET STATUS {}", s.to_uppercase())),
+        );
+        parts.extend(
+            self.home_database
+                .map(|h| format!("SET HOME DATABASE {}", h)),
+        );
+        join_parts(
+            format!(
+                "CREATE {}USER {}{}",
+                or_replace_str(self.or_replace),
+                self.name,
+                if_not_exists_str(self.if_not_exists)
+            ),
+            parts,
+        )
+    }
+
+    fn parse_change_required(input: &'a str) -> IResult<&str, bool> {
+        map(
+            tuple((
+                keyword("CHANGE"),
+                multispace1,
+                optional(tuple((keyword("NOT"), multispace1))),
+                keyword("REQUIRED"),
+            )),
+            |(_, _, not, _)| not.is_none(),
+        )(input)
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                keyword("CREATE"),
+                multispace1,
+                parse_or_replace,
+                keyword("USER"),
+                multispace1,
+                token::parse,
+                parse_if_not_exists,
+                tuple((multispace1, keyword("SET"), multispace1)),
+                optional(tuple((
+                    alt((
+                        map(keyword("ENCRYPTED"), |_| true),
+                        map(keyword("PLAINTEXT"), |_| false),
+                    )),
+                    multispace1,
+                ))),
+                tuple((keyword("PASSWORD"), multispace1)),
+                Expression::parse,
+                optional(preceded(multispace0, Self::parse_change_required)),
+                optional(preceded(
+                    tuple((
+                        multispace1,
+                        keyword("SET"),
+                        multispace1,
+                        keyword("STATUS"),
+                        multispace1,
+                    )),
+                    alt((keyword("ACTIVE"), keyword("SUSPENDED"))),
+                )),
+                optional(preceded(
+                    tuple((
+                        multispace1,
+                        keyword("SET"),
+                        multispace1,
+                        keyword("HOME"),
+                        multispace1,
+                        keyword("DATABASE"),
+                        multispace1,
+                    )),
+                    parse_database_name,
+                )),
+            )),
+            |(
+                _,
+                _,
+                or_replace,
+                _,
+                _,
+                name,
+                if_not_exists,
+                _,
+                encrypted,
+                _,
+                password,
+                change_required,
+                status,
+                home_database,
+            )| CreateUser {
+                or_replace,
+                name,
+                if_not_exists,
+                encrypted: encrypted.map(|(encrypted, _)| encrypted),
+                password,
+                change_required,
+                status,
+                home_database,
+            },
+        )(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PrivilegeAction {
+    Grant,
+    Deny,
+    Revoke,
+    RevokeGrant,
+    RevokeDeny,
+}
+
+impl PrivilegeAction {
+    pub fn format(&self) -> String {
+        match self {
+            PrivilegeAction::Grant => "GRANT",
+            PrivilegeAction::Deny => "DENY",
+            PrivilegeAction::Revoke => "REVOKE",
+            PrivilegeAction::RevokeGrant => "REVOKE GRANT",
+            PrivilegeAction::RevokeDeny => "REVOKE DENY",
+        }
+        .to_string()
+    }
+
+    fn preposition(&self) -> &str {
+        match self {
+            PrivilegeAction::Grant | PrivilegeAction::Deny => "TO",
+            _ => "FROM",
+        }
+    }
+
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        alt((
+            map(keyword("GRANT"), |_| PrivilegeAction::Grant),
+            map(keyword("DENY"), |_| PrivilegeAction::Deny),
+            preceded(
+                keyword("REVOKE"),
+                map(
+                    optional(preceded(
+                        multispace1,
+                        alt((
+                            map(keyword("GRANT"), |_| PrivilegeAction::RevokeGrant),
+                            map(keyword("DENY"), |_| PrivilegeAction::RevokeDeny),
+                        )),
+                    )),
+                    |action| action.unwrap_or(PrivilegeAction::Revoke),
+                ),
+            ),
+        ))(input)
+    }
+}
+
+// The privileges, each spelled as its sequence of keywords.
+const PRIVILEGES: [&[&str]; 104] = [
+    &["ACCESS"],
+    &["ALIAS", "MANAGEMENT"],
+    &["ALL"],
+    &["ALL", "DATABASE", "PRIVILEGES"],
+    &["ALL", "DBMS", "PRIVILEGES"],
+    &["ALL", "GRAPH", "PRIVILEGES"],
+    &["ALL", "PRIVILEGES"],
+    &["ALTER", "ALIAS"],
+    &["ALTER", "DATABASE"],
+    &["ALTER", "USER"],
+    &["ASSIGN", "PRIVILEGE"],
+    &["ASSIGN", "ROLE"],
+    &["COMPOSITE", "DATABASE", "MANAGEMENT"],
+    &["CONSTRAINT"],
+    &["CONSTRAINT", "MANAGEMENT"],
+    &["CONSTRAINTS"],
+    &["CREATE"],
+    &["CREATE", "ALIAS"],
+    &["CREATE", "COMPOSITE", "DATABASE"],
+    &["CREATE", "CONSTRAINT"],
+    &["CREATE", "CONSTRAINTS"],
+    &["CREATE", "DATABASE"],
+    &["CREATE", "INDEX"],
+    &["CREATE", "INDEXES"],
+    &["CREATE", "NEW", "LABEL"],
+    &["CREATE", "NEW", "NAME"],
+    &["CREATE", "NEW", "NODE", "LABEL"],
+    &["CREATE", "NEW", "PROPERTY", "NAME"],
+    &["CREATE", "NEW", "RELATIONSHIP", "TYPE"],
+    &["CREATE", "NEW", "TYPE"],
+    &["CREATE", "ROLE"],
+    &["CREATE", "USER"],
+    &["DATABASE", "MANAGEMENT"],
+    &["DELETE"],
+    &["DROP", "ALIAS"],
+    &["DROP", "COMPOSITE", "DATABASE"],
+    &["DROP", "CONSTRAINT"],
+    &["DROP", "CONSTRAINTS"],
+    &["DROP", "DATABASE"],
+    &["DROP", "INDEX"],
+    &["DROP", "INDEXES"],
+    &["DROP", "ROLE"],
+    &["DROP", "USER"],
+    &["EXECUTE", "ADMIN", "PROCEDURES"],
+    &["EXECUTE", "ADMINISTRATOR", "PROCEDURES"],
+    &["EXECUTE", "BOOSTED", "FUNCTION"],
+    &["EXECUTE", "BOOSTED", "FUNCTIONS"],
+    &["EXECUTE", "BOOSTED", "PROCEDURE"],
+    &["EXECUTE", "BOOSTED", "PROCEDURES"],
+    &["EXECUTE", "BOOSTED", "USER", "DEFINED", "FUNCTION"],
+    &["EXECUTE", "BOOSTED", "USER", "DEFINED", "FUNCTIONS"],
+    &["EXECUTE", "FUNCTION"],
+    &["EXECUTE", "FUNCTIONS"],
+    &["EXECUTE", "PROCEDURE"],
+    &["EXECUTE", "PROCEDURES"],
+    &["EXECUTE", "USER", "DEFINED", "FUNCTION"],
+    &["EXECUTE", "USER", "DEFINED", "FUNCTIONS"],
+    &["IMPERSONATE"],
+    &["INDEX"],
+    &["INDEX", "MANAGEMENT"],
+    &["INDEXES"],
+    &["LOAD"],
+    &["MATCH"],
+    &["MERGE"],
+    &["NAME"],
+    &["NAME", "MANAGEMENT"],
+    &["PRIVILEGE", "MANAGEMENT"],
+    &["READ"],
+    &["REMOVE", "LABEL"],
+    &["REMOVE", "PRIVILEGE"],
+    &["REMOVE", "ROLE"],
+    &["RENAME", "ROLE"],
+    &["RENAME", "USER"],
+    &["ROLE", "MANAGEMENT"],
+    &["SERVER", "MANAGEMENT"],
+    &["SET", "DATABASE", "ACCESS"],
+    &["SET", "LABEL"],
+    &["SET", "PASSWORD"],
+    &["SET", "PASSWORDS"],
+    &["SET", "PROPERTY"],
+    &["SET", "USER", "HOME", "DATABASE"],
+    &["SET", "USER", "STATUS"],
+    &["SHOW", "ALIAS"],
+    &["SHOW", "CONSTRAINT"],
+    &["SHOW", "CONSTRAINTS"],
+    &["SHOW", "INDEX"],
+    &["SHOW", "INDEXES"],
+    &["SHOW", "PRIVILEGE"],
+    &["SHOW", "ROLE"],
+    &["SHOW", "SERVER"],
+    &["SHOW", "SERVERS"],
+    &["SHOW", "SETTING"],
+    &["SHOW", "SETTINGS"],
+    &["SHOW", "TRANSACTION"],
+    &["SHOW", "TRANSACTIONS"],
+    &["SHOW", "USER"],
+    &["START"],
+    &["STOP"],
+    &["TERMINATE", "TRANSACTION"],
+    &["TERMINATE", "TRANSACTIONS"],
+    &["TRANSACTION", "MANAGEMENT"],
+    &["TRAVERSE"],
+    &["USER", "MANAGEMENT"],
+    &["WRITE"],
+];
+
+fn parse_phrase<'a>(
+    input: &'a str,
+    phrase: &'static [&'static str],
+) -> IResult<&'a str, &'static [&'static str]> {
+    let mut rest = input;
+    for (i, word) in phrase.iter().enumerate() {
+        if i > 0 {
+            rest = multispace1(rest)?.0;
+        }
+        rest = keyword(word)(rest)?.0;
+    }
+    Ok((rest, phrase))
+}
+
+// Procedure, function and setting names may contain `*` and `?` globs.
+fn parse_globs(input: &str) -> IResult<&str, Vec<&str>> {
+    separated_list1(
+        tuple((multispace0, tag(","), multispace0)),
+        verify(
+            take_while1(|c: char| c.is_alphanumeric() || "_.*?".contains(c)),
+            |glob: &str| !glob.eq_ignore_ascii_case("ON"),
+        ),
+    )(input)
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PrivilegeArgument<'a> {
+    Properties(Vec<&'a str>),
+    Users(Vec<&'a str>),
+    Names(Vec<&'a str>),
+}
+
+impl<'a> PrivilegeArgument<'a> {
+    pub fn format(&self) -> String {
+        match self {
+            PrivilegeArgument::Properties(properties) => format!("{{{}}}", properties.join(", ")),
+            PrivilegeArgument::Users(users) => format!("({})", users.join(", ")),
+            PrivilegeArgument::Names(names) => names.join(", "),
+        }
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        alt((
+            map(
+                delimited(
+                    tuple((tag("{"), multispace0)),
+                    parse_star_or_names,
+                    tuple((multispace0, tag("}"))),
+                ),
+                PrivilegeArgument::Properties,
+            ),
+            map(
+                delimited(
+                    tuple((tag("("), multispace0)),
+                    parse_star_or_names,
+                    tuple((multispace0, tag(")"))),
+                ),
+                PrivilegeArgument::Users,
+            ),
+            map(parse_globs, PrivilegeArgument::Names),
+        ))(input)
+    }
+}
+
+// A privilege such as `READ {name}`, `SET LABEL Person` or
+// `EXECUTE PROCEDURE db.*`.
+#[derive(Debug, PartialEq)]
+pub struct Privilege<'a> {
+    name: &'static [&'static str],
+    argument: Option<PrivilegeArgument<'a>>,
+}
+
+impl<'a> Privilege<'a> {
+    pub fn format(&self) -> String {
+        std::iter::once(self.name.join(" "))
+            .chain(self.argument.iter().map(|a| a.format()))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    // The longest matching phrase wins, so `ALL` does not cut
+    // `ALL GRAPH PRIVILEGES` short.
+    fn parse_name(input: &'a str) -> IResult<&str, &'static [&'static str]> {
+        PRIVILEGES
+            .iter()
+            .filter_map(|phrase| parse_phrase(input, phrase).ok())
+            .max_by_key(|(_, phrase)| phrase.len())
+            .ok_or_else(|| nom::Err::Error(nom::error::Error::new(input, ErrorKind::Tag)))
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                Self::parse_name,
+                optional(preceded(multispace0, PrivilegeArgument::parse)),
+            )),
+            |(name, argument)| Privilege { name, argument },
+        )(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PrivilegeTarget<'a> {
+    Dbms,
+    AllData,
+    Url(&'a str),
+    Cidr(&'a str),
+    Home(&'static str),
+    Default(&'static str),
+    Named {
+        kind: &'static str,
+        names: Vec<&'a str>,
+    },
+}
+
+impl<'a> PrivilegeTarget<'a> {
+    pub fn format(&self) -> String {
+        match self {
+            PrivilegeTarget::Dbms => "DBMS".to_string(),
+            PrivilegeTarget::AllData => "ALL DATA".to_string(),
+            PrivilegeTarget::Url(url) => format!("URL {}", url),
+            PrivilegeTarget::Cidr(cidr) => format!("CIDR {}", cidr),
+            PrivilegeTarget::Home(kind) => format!("HOME {}", kind),
+            PrivilegeTarget::Default(kind) => format!("DEFAULT {}", kind),
+            PrivilegeTarget::Named { kind, names } => format!("{} {}", kind, names.join(", ")),
+        }
+    }
+
+    fn parse_string(input: &'a str) -> IResult<&str, &str> {
+        map(string::String::parse, |s| s.0)(input)
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        alt((
+            map(keyword("DBMS"), |_| PrivilegeTarget::Dbms),
+            map(
+                tuple((keyword("ALL"), multispace1, keyword("DATA"))),
+                |_| PrivilegeTarget::AllData,
+            ),
+            map(
+                preceded(tuple((keyword("URL"), multispace1)), Self::parse_string),
+                PrivilegeTarget::Url,
+            ),
+            map(
+                preceded(tuple((keyword("CIDR"), multispace1)), Self::parse_string),
+                PrivilegeTarget::Cidr,
+            ),
+            map(
+                preceded(
+                    tuple((keyword("HOME"), multispace1)),
+                    parse_keyword_of(&["GRAPH", "DATABASE"]),
+                ),
+                PrivilegeTarget::Home,
+            ),
+            map(
+                preceded(
+                    tuple((keyword("DEFAULT"), multispace1)),
+                    parse_keyword_of(&["GRAPH", "DATABASE"]),
+                ),
+                PrivilegeTarget::Default,
+            ),
+            map(
+                tuple((
+                    parse_keyword_of(&["GRAPHS", "GRAPH", "DATABASES", "DATABASE"]),
+                    multispace1,
+                    alt((
+                        map(tag("*"), |star| vec![star]),
+                        separated_list1(
+                            tuple((multispace0, tag(","), multispace0)),
+                            parse_database_name,
+                        ),
+                    )),
+                )),
+                |(kind, _, names)| PrivilegeTarget::Named { kind, names },
+            ),
+        ))(input)
+    }
+}
+
+// Narrows a graph privilege to some labels or relationship types, as in
+// `NODES Person` or `RELATIONSHIPS *`.
+#[derive(Debug, PartialEq)]
+pub struct PrivilegeQualifier<'a> {
+    kind: &'static str,
+    names: Vec<&'a str>,
+}
+
+impl<'a> PrivilegeQualifier<'a> {
+    pub fn format(&self) -> String {
+        format!("{} {}", self.kind, self.names.join(", "))
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                parse_keyword_of(&[
+                    "NODES",
+                    "NODE",
+                    "RELATIONSHIPS",
+                    "RELATIONSHIP",
+                    "ELEMENTS",
+                    "ELEMENT",
+                ]),
+                multispace1,
+                parse_star_or_names,
+            )),
+            |(kind, _, names)| PrivilegeQualifier { kind, names },
+        )(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PrivilegeScope<'a> {
+    target: PrivilegeTarget<'a>,
+    qualifier: Option<PrivilegeQualifier<'a>>,
+}
+
+impl<'a> PrivilegeScope<'a> {
+    pub fn format(&self) -> String {
+        std::iter::once(self.target.format())
+            .chain(self.qualifier.iter().map(|q| q.format()))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                PrivilegeTarget::parse,
+                optional(preceded(multispace1, PrivilegeQualifier::parse)),
+            )),
+            |(target, qualifier)| PrivilegeScope { target, qualifier },
+        )(input)
+    }
+}
+
+// Keywords are printed in upper case, and names, labels and property lists as
+// written.
+#[derive(Debug, PartialEq)]
+pub enum PrivilegeCommand<'a> {
+    Role {
+        action: PrivilegeAction,
+        roles: Vec<&'a str>,
+        users: Vec<&'a str>,
+    },
+    Privilege {
+        action: PrivilegeAction,
+        privilege: Privilege<'a>,
+        scope: PrivilegeScope<'a>,
+        roles: Vec<&'a str>,
+    },
+}
+
+impl<'a> PrivilegeCommand<'a> {
+    pub fn format(&self) -> String {
+        match self {
+            PrivilegeCommand::Role {
+                action,
+                roles,
+                users,
+            } => format!(
+                "{} {} {} {} {}",
+                action.format(),
+                if roles.len() == 1 { "ROLE" } else { "ROLES" },
+                roles.join(", "),
+                action.preposition(),
+                users.join(", ")
+            ),
+            PrivilegeCommand::Privilege {
+                action,
+                privilege,
+                scope,
+                roles,
+            } => format!(
+                "{} {} ON {} {} {}",
+                action.format(),
+                privilege.format(),
+                scope.format(),
+                action.preposition(),
+                roles.join(", ")
+            ),
+        }
+    }
+
+    fn parse_role(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                PrivilegeAction::parse,
+                multispace1,
+                alt((keyword("ROLES"), keyword("ROLE"))),
+                multispace1,
+                parse_names,
+                multispace1,
+                alt((keyword("TO"), keyword("FROM"))),
+                multispace1,
+                parse_names,
+            )),
+            |(action, _, _, _, roles, _, _, _, users)| PrivilegeCommand::Role {
+                action,
+                roles,
+                users,
+            },
+        )(input)
+    }
+
+    fn parse_privilege(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                PrivilegeAction::parse,
+                multispace1,
+                Privilege::parse,
+                multispace1,
+                keyword("ON"),
+                multispace1,
+                PrivilegeScope::parse,
+                multispace1,
+                alt((keyword("TO"), keyword("FROM"))),
+                multispace1,
+                parse_names,
+            )),
+            |(action, _, privilege, _, _, _, scope, _, _, _, roles)| PrivilegeCommand::Privilege {
+                action,
+                privilege,
+                scope,
+                roles,
+            },
+        )(input)
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        alt((Self::parse_role, Self::parse_privilege))(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum AdministrationCommand<'a> {
+    Show(ShowCommand<'a>),
+    CreateDatabase(CreateDatabase<'a>),
+    DropDatabase(DropDatabase<'a>),
+    AlterDatabase(AlterDatabase<'a>),
+    CreateUser(CreateUser<'a>),
+    Privilege(PrivilegeCommand<'a>),
+}
+
+impl<'a> AdministrationCommand<'a> {
+    pub fn format(&self) -> String {
+        match self {
+            AdministrationCommand::Show(s) => s.format(),
+            AdministrationCommand::CreateDatabase(c) => c.format(),
+            AdministrationCommand::DropDatabase(d) => d.format(),
+            AdministrationCommand::AlterDatabase(a) => a.format(),
+            AdministrationCommand::CreateUser(c) => c.format(),
+            AdministrationCommand::Privilege(p) => p.format(),
+        }
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        alt((
+            map(ShowCommand::parse, AdministrationCommand::Show),
+            map(CreateDatabase::parse, AdministrationCommand::CreateDatabase),
+            map(DropDatabase::parse, AdministrationCommand::DropDatabase),
+            map(AlterDatabase::parse, AdministrationCommand::AlterDatabase),
+            map(CreateUser::parse, AdministrationCommand::CreateUser),
+            map(PrivilegeCommand::parse, AdministrationCommand::Privilege),
+        ))(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(command: &str) -> String {
+        AdministrationCommand::parse(command).unwrap().1.format()
+    }
+
+    #[test]
+    fn format_show() {
+        assert_eq!("SHOW INDEXES", format("show index"));
+        assert_eq!(
+            "SHOW RANGE INDEXES YIELD name, type\nWHERE type = 'RANGE'\nRETURN name",
+            format("SHOW range INDEXES YIELD name, type WHERE type = 'RANGE' RETURN name")
+        );
+        assert_eq!(
+            "SHOW PROCEDURES\nWHERE name = 'db.labels'",
+            format("SHOW PROCEDURES WHERE name = 'db.labels'")
+        );
+        assert_eq!("SHOW DATABASE neo4j", format("show database neo4j"));
+    }
+
+    #[test]
+    fn parse_show_constraints() {
+        let expected = Ok((
+            ";",
+            AdministrationCommand::Show(ShowCommand {
+                modifier: Some("ALL"),
+                object: ShowObject::Constraints,
+                r#yield: None,
+                r#where: None,
+                r#return: None,
+            }),
+        ));
+        let actual = AdministrationCommand::parse("SHOW ALL CONSTRAINTS;");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_databases() {
+        assert_eq!(
+            "CREATE OR REPLACE DATABASE sales.eu IF NOT EXISTS WAIT 10 SECONDS",
+            format("create or replace database sales.eu if not exists wait 10 sec")
+        );
+        assert_eq!(
+            "DROP DATABASE sales IF EXISTS DESTROY DATA NOWAIT",
+            format("DROP DATABASE sales IF EXISTS destroy data nowait")
+        );
+        assert_eq!(
+            "ALTER DATABASE sales SET ACCESS READ ONLY",
+            format("ALTER DATABASE sales SET ACCESS READ ONLY")
+        );
+        assert_eq!(
+            "ALTER DATABASE sales SET TOPOLOGY 3 PRIMARIES 2 SECONDARIES",
+            format("alter database sales set topology 3 primary 2 secondary")
+        );
+    }
+
+    #[test]
+    fn format_create_user() {
+        assert_eq!(
+            "CREATE USER alice IF NOT EXISTS\n  SET PASSWORD $password CHANGE NOT REQUIRED\n  SET STATUS ACTIVE\n  SET HOME DATABASE sales",
+            format("CREATE USER alice IF NOT EXISTS SET PASSWORD $password CHANGE NOT REQUIRED SET STATUS active SET HOME DATABASE sales")
+        );
+        assert_eq!(
+            "CREATE USER bob SET PASSWORD 'secret'",
+            format("create user bob set password 'secret'")
+        );
+    }
+
+    #[test]
+    fn format_privileges() {
+        assert_eq!(
+            "GRANT ROLES reader, writer TO alice",
+            format("grant role reader,writer to alice")
+        );
+        assert_eq!(
+            "REVOKE ROLE reader FROM alice",
+            format("REVOKE ROLE reader FROM alice")
+        );
+        assert_eq!(
+            "GRANT TRAVERSE ON GRAPH neo4j NODES Person TO reader",
+            format("GRANT TRAVERSE\n  ON GRAPH neo4j NODES Person\n  TO reader")
+        );
+        assert_eq!(
+            "REVOKE DENY READ {salary} ON GRAPH * FROM analyst",
+            format("REVOKE DENY READ {salary} ON GRAPH * FROM analyst")
+        );
+        assert_eq!(
+            "GRANT MATCH {*} ON GRAPH neo4j TO reader",
+            format("grant match {*} on graph neo4j to reader")
+        );
+        assert_eq!(
+            "GRANT ALL DATABASE PRIVILEGES ON DATABASE movies TO admin",
+            format("grant all database privileges on database movies to admin")
+        );
+        assert_eq!(
+            "DENY SET LABEL Role ON GRAPH * NODES User, Role TO editor",
+            format("deny set label Role on graph * nodes User, Role to editor")
+        );
+        assert_eq!(
+            "GRANT READ {*} ON GRAPH movies NODES Label TO reader",
+            format("grant read {*} on graph movies nodes Label to reader")
+        );
+        assert_eq!(
+            "GRANT CREATE NEW NODE LABEL ON HOME DATABASE TO editor",
+            format("grant create new node label on home database to editor")
+        );
+        assert_eq!(
+            "GRANT EXECUTE PROCEDURE apoc.*, db.labels ON DBMS TO developer",
+            format("grant execute procedure apoc.*,db.labels on dbms to developer")
+        );
+        assert_eq!(
+            "DENY IMPERSONATE (alice) ON DBMS TO guest",
+            format("deny impersonate (alice) on dbms to guest")
+        );
+        assert_eq!(
+            "GRANT LOAD ON URL 'https://data.example.com/*' TO importer",
+            format("grant load on url 'https://data.example.com/*' to importer")
+        );
+        assert!(PrivilegeCommand::parse("GRANT FLY ON GRAPH neo4j TO reader").is_err());
+    }
+}
@@ -1,23 +1,62 @@
-use nom::{combinator::map, IResult};
+use nom::{
+    character::complete::multispace0,
+    combinator::map,
+    sequence::{preceded, tuple},
+    IResult,
+};
 pub mod list_comprehension;
+pub mod subquery_expression;
 
-use crate::{literal::Literal, parameter::Parameter, symbolic_name};
+use crate::{
+    format_options::FormatOptions, literal::Literal, parameter::Parameter, shared::keyword,
+    shortest_path::ShortestPath, symbolic_name,
+};
+
+use self::subquery_expression::SubqueryExpression;
 
 #[derive(Debug, PartialEq)]
 pub enum Atom<'a> {
     Literal(Literal<'a>),
     Parameter(Parameter<'a>),
+    ShortestPath(ShortestPath<'a>),
+    Exists(SubqueryExpression<'a>),
+    Count(SubqueryExpression<'a>),
+    Collect(SubqueryExpression<'a>),
     Variable(&'a str),
 }
 
 impl<'a> Atom<'a> {
     pub fn format(&self) -> String {
+        self.format_with(&FormatOptions::default())
+    }
+
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        self.format_at(options, 0)
+    }
+
+    pub fn format_at(&self, options: &FormatOptions, column: usize) -> String {
+        let subquery = |word: &str, s: &SubqueryExpression| {
+            format!("{} {}", word, s.format_at(options, column + word.len() + 1))
+        };
         match self {
-            Atom::Literal(l) => l.format(),
+            Atom::Literal(l) => l.format_at(options, column),
             Atom::Parameter(p) => p.format(),
+            Atom::ShortestPath(s) => s.format_with(options),
+            Atom::Exists(s) => subquery("EXISTS", s),
+            Atom::Count(s) => subquery("COUNT", s),
+            Atom::Collect(s) => subquery("COLLECT", s),
             Atom::Variable(v) => v.to_string(),
         }
     }
+    fn parse_subquery(
+        word: &'static str,
+    ) -> impl FnMut(&'a str) -> IResult<&'a str, SubqueryExpression<'a>> {
+        preceded(
+            tuple((keyword(word), multispace0)),
+            SubqueryExpression::parse,
+        )
+    }
+
     pub fn parse(input: &'a str) -> IResult<&str, Self> {
         if let Ok((input, literal)) = Literal::parse(input) {
             return Ok((input, Atom::Literal(literal)));
@@ -27,6 +66,22 @@ impl<'a> Atom<'a> {
             return Ok((input, Atom::Parameter(parameter)));
         }
 
+        if let Ok((input, shortest_path)) = ShortestPath::parse(input) {
+            return Ok((input, Atom::ShortestPath(shortest_path)));
+        }
+
+        if let Ok((input, exists)) = Self::parse_subquery("EXISTS")(input) {
+            return Ok((input, Atom::Exists(exists)));
+        }
+
+        if let Ok((input, count)) = Self::parse_subquery("COUNT")(input) {
+            return Ok((input, Atom::Count(count)));
+        }
+
+        if let Ok((input, collect)) = Self::parse_subquery("COLLECT")(input) {
+            return Ok((input, Atom::Collect(collect)));
+        }
+
         map(symbolic_name::parse, Atom::Variable)(input)
     }
 }
@@ -0,0 +1,154 @@
+use nom::{
+    bytes::complete::tag,
+    character::complete::multispace0,
+    combinator::{map, verify},
+    sequence::{delimited, preceded, tuple},
+    IResult,
+};
+
+use crate::{
+    cypher::RegularQuery,
+    format_options::FormatOptions,
+    pattern::PatternPart,
+    shared::{indent, optional, MAX_LINE_WIDTH},
+    single_part_query::{parse_read_parts, ReadingClause},
+    subclause::r#where::Where,
+};
+
+#[derive(Debug, PartialEq)]
+pub enum SubqueryBody<'a> {
+    Query(RegularQuery<'a>),
+    Reading(Vec<ReadingClause<'a>>),
+    Pattern(Vec<PatternPart<'a>>, Option<Where<'a>>),
+}
+
+impl<'a> SubqueryBody<'a> {
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        match self {
+            SubqueryBody::Query(query) => query.format_with(options),
+            SubqueryBody::Reading(clauses) => clauses
+                .iter()
+                .map(|c| c.format_with(options))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            SubqueryBody::Pattern(patterns, r#where) => format!(
+                "{}{}",
+                PatternPart::format_list(patterns, options),
+                r#where
+                    .as_ref()
+                    .map(|w| format!("\n{}", w.format_with(options)))
+                    .unwrap_or_default()
+            ),
+        }
+    }
+
+    // Full queries are always printed as a block.
+    pub fn format_inline(&self, options: &FormatOptions) -> Option<String> {
+        match self {
+            SubqueryBody::Query(_) => None,
+            SubqueryBody::Reading(clauses) => clauses
+                .iter()
+                .map(|c| c.format_inline(options))
+                .collect::<Option<Vec<_>>>()
+                .map(|clauses| clauses.join(" ")),
+            SubqueryBody::Pattern(patterns, r#where) => Some(format!(
+                "{}{}",
+                patterns
+                    .iter()
+                    .map(|p| p.format_with(options))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                r#where
+                    .as_ref()
+                    .map(|w| format!(" {}", w.format_with(options)))
+                    .unwrap_or_default()
+            )),
+        }
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        if let Ok((input, query)) = RegularQuery::parse(input) {
+            return Ok((input, SubqueryBody::Query(query)));
+        }
+
+        if let Ok((input, clauses)) =
+            verify(parse_read_parts, |clauses: &[_]| !clauses.is_empty())(input)
+        {
+            return Ok((input, SubqueryBody::Reading(clauses)));
+        }
+
+        map(
+            tuple((
+                PatternPart::parse_list,
+                optional(preceded(multispace0, Where::parse)),
+            )),
+            |(patterns, r#where)| SubqueryBody::Pattern(patterns, r#where),
+        )(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SubqueryExpression<'a>(pub SubqueryBody<'a>);
+
+impl<'a> SubqueryExpression<'a> {
+    pub fn format(&self) -> String {
+        self.format_at(&FormatOptions::default(), 0)
+    }
+
+    pub fn format_at(&self, options: &FormatOptions, column: usize) -> String {
+        let inline = self
+            .0
+            .format_inline(options)
+            .map(|body| format!("{{ {} }}", body))
+            .filter(|inline| !inline.contains('\n') && column + inline.len() <= MAX_LINE_WIDTH);
+        match inline {
+            Some(inline) => inline,
+            None => format!("{{\n{}\n}}", indent(&self.0.format_with(options))),
+        }
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            delimited(
+                tuple((tag("{"), multispace0)),
+                SubqueryBody::parse,
+                tuple((multispace0, tag("}"))),
+            ),
+            SubqueryExpression,
+        )(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_subquery_expression_inline() {
+        let expected = "{ MATCH (a) -[:KNOWS]-> (b) WHERE b.age > 18 }";
+        let actual = SubqueryExpression::parse("{MATCH (a)-[:KNOWS]->(b) WHERE b.age > 18}")
+            .unwrap()
+            .1
+            .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_subquery_expression_block() {
+        let expected = "{\n  MATCH\n  (person) -[:HAS_DOG]-> (dog:Dog)\n  WHERE dog.name = 'Ozzy'\n  RETURN dog.name AS name\n}";
+        let actual = SubqueryExpression::parse(
+            "{ MATCH (person)-[:HAS_DOG]->(dog:Dog) WHERE dog.name = 'Ozzy' RETURN dog.name AS name }",
+        )
+        .unwrap()
+        .1
+        .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_subquery_expression_pattern() {
+        let (input, actual) = SubqueryExpression::parse("{ (a)-->(b) } data").unwrap();
+        assert_eq!(" data", input);
+        assert!(matches!(actual.0, SubqueryBody::Pattern(ref p, None) if p.len() == 1));
+    }
+}
@@ -0,0 +1,82 @@
+use nom::{character::complete::multispace0, combinator::map, sequence::tuple, IResult};
+
+use crate::{format_options::FormatOptions, pattern::PatternPart, shared::keyword};
+
+#[derive(Debug, PartialEq)]
+pub struct Create<'a> {
+    patterns: Vec<PatternPart<'a>>,
+}
+
+impl<'a> Create<'a> {
+    pub fn format(&self) -> String {
+        self.format_with(&FormatOptions::default())
+    }
+
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        format!(
+            "CREATE\n{}",
+            PatternPart::format_list(&self.patterns, options)
+        )
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((keyword("CREATE"), multispace0, PatternPart::parse_list)),
+            |(_, _, patterns)| Create { patterns },
+        )(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        entity::Entity,
+        label::{Label, LabelExpression},
+        node::Node,
+        pattern::{AnonymousPatternPart, Pattern},
+    };
+
+    use super::*;
+
+    #[test]
+    fn parse_create() {
+        let expected = Ok((
+            " data",
+            Create {
+                patterns: vec![
+                    PatternPart {
+                        variable: None,
+                        selector: None,
+                        pattern: AnonymousPatternPart::Pattern(Pattern(vec![Entity::Node(Node {
+                            variable: Some("a"),
+                            labels: Some(LabelExpression::Label(Label("ALabel"))),
+                            properties: None,
+                        })])),
+                    },
+                    PatternPart {
+                        variable: None,
+                        selector: None,
+                        pattern: AnonymousPatternPart::Pattern(Pattern(vec![Entity::Node(Node {
+                            variable: Some("b"),
+                            labels: None,
+                            properties: None,
+                        })])),
+                    },
+                ],
+            },
+        ));
+
+        let actual = Create::parse("create (a:ALabel),(b) data");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_create() {
+        let expected = "CREATE\n(a:Person {name: 'Ann'}) -[:KNOWS]-> (b), (c)";
+        let actual = Create::parse("CREATE (a:Person{name:'Ann'})-[:KNOWS]->(b) , (c)")
+            .unwrap()
+            .1
+            .format();
+        assert_eq!(expected, actual);
+    }
+}
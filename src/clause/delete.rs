@@ -0,0 +1,80 @@
+use nom::{
+    bytes::complete::tag,
+    character::complete::{multispace0, multispace1, space0},
+    combinator::map,
+    multi::separated_list1,
+    sequence::{terminated, tuple},
+    IResult,
+};
+
+use crate::{
+    expression::Expression,
+    shared::{keyword, optional},
+};
+
+#[derive(Debug, PartialEq)]
+pub struct Delete<'a> {
+    detach: bool,
+    expressions: Vec<Expression<'a>>,
+}
+
+impl<'a> Delete<'a> {
+    fn detach_str(&self) -> &str {
+        if self.detach {
+            return "DETACH ";
+        }
+        ""
+    }
+
+    pub fn format(&self) -> String {
+        format!(
+            "{}DELETE {}",
+            self.detach_str(),
+            self.expressions
+                .iter()
+                .map(|e| e.format())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                optional(terminated(keyword("DETACH"), multispace1)),
+                keyword("DELETE"),
+                multispace1,
+                separated_list1(tuple((space0, tag(","), multispace0)), Expression::parse),
+            )),
+            |(detach, _, _, expressions)| Delete {
+                detach: detach.is_some(),
+                expressions,
+            },
+        )(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_delete() {
+        let expected = Ok((
+            "data",
+            Delete {
+                detach: false,
+                expressions: vec![Expression::variable("a"), Expression::variable("b")],
+            },
+        ));
+        let actual = Delete::parse("delete a,b data");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_detach_delete() {
+        let expected = "DETACH DELETE n, r";
+        let actual = Delete::parse("detach\n  delete n ,r").unwrap().1.format();
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,97 @@
+use nom::{
+    bytes::complete::tag,
+    character::complete::multispace0,
+    combinator::{map, verify},
+    sequence::tuple,
+    IResult,
+};
+
+use crate::{
+    expression::Expression,
+    filter_expression::parse_id_in_coll,
+    format_options::FormatOptions,
+    shared::{indent, keyword},
+    single_part_query::{parse_updating_parts, UpdatingClause},
+};
+
+#[derive(Debug, PartialEq)]
+pub struct Foreach<'a> {
+    variable: &'a str,
+    expression: Expression<'a>,
+    clauses: Vec<UpdatingClause<'a>>,
+}
+
+impl<'a> Foreach<'a> {
+    pub fn format(&self) -> String {
+        self.format_with(&FormatOptions::default())
+    }
+
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        let clauses = self
+            .clauses
+            .iter()
+            .map(|c| c.format_with(options))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let head = format!(
+            "FOREACH ({} IN {} |",
+            self.variable,
+            self.expression.format()
+        );
+
+        if self.clauses.len() == 1 && !clauses.contains('\n') {
+            return format!("{} {})", head, clauses);
+        }
+        format!("{}\n{}\n)", head, indent(&clauses))
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                keyword("FOREACH"),
+                multispace0,
+                tag("("),
+                multispace0,
+                parse_id_in_coll,
+                multispace0,
+                tag("|"),
+                multispace0,
+                verify(parse_updating_parts, |parts: &[_]| !parts.is_empty()),
+                multispace0,
+                tag(")"),
+            )),
+            |(_, _, _, _, (variable, expression), _, _, _, clauses, _, _)| Foreach {
+                variable,
+                expression,
+                clauses,
+            },
+        )(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_foreach_single() {
+        let expected = "FOREACH (n IN $nodes | SET n.marked = TRUE)";
+        let actual = Foreach::parse("foreach(n in $nodes|set n.marked = true)")
+            .unwrap()
+            .1
+            .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_foreach_nested() {
+        let expected = "FOREACH (name IN $names |\n  CREATE\n  (p:Person {name: name})\n  FOREACH (tag IN $tags | MERGE (t:Tag {name: tag}))\n)";
+        let actual = Foreach::parse(
+            "FOREACH (name IN $names | CREATE (p:Person {name: name}) FOREACH (tag IN $tags | MERGE (t:Tag {name: tag})))",
+        )
+        .unwrap()
+        .1
+        .format();
+        assert_eq!(expected, actual);
+    }
+}
@@ -1,2 +1,70 @@
+use nom::{
+    character::complete::{multispace0, multispace1},
+    combinator::map,
+    sequence::{preceded, tuple},
+    IResult,
+};
+
+use crate::{
+    procedure_invocation::ProcedureInvocation,
+    shared::{keyword, optional},
+    subclause::r#yield::Yield,
+};
+
 #[derive(Debug, PartialEq)]
-pub struct InQueryCall {}
+pub struct InQueryCall<'a> {
+    invocation: ProcedureInvocation<'a>,
+    r#yield: Option<Yield<'a>>,
+}
+
+impl<'a> InQueryCall<'a> {
+    fn yield_str(&self) -> String {
+        self.r#yield
+            .as_ref()
+            .map(|y| format!(" {}", y.format()))
+            .unwrap_or_default()
+    }
+
+    pub fn format(&self) -> String {
+        format!(
+            "{}{}",
+            self.invocation.format_prefixed("CALL "),
+            self.yield_str()
+        )
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                keyword("CALL"),
+                multispace1,
+                ProcedureInvocation::parse_explicit,
+                optional(preceded(multispace0, Yield::parse)),
+            )),
+            |(_, _, invocation, r#yield)| InQueryCall {
+                invocation,
+                r#yield,
+            },
+        )(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_in_query_call() {
+        let expected = "CALL db.labels() YIELD label AS l";
+        let actual = InQueryCall::parse("call db.labels ( )  yield label as l")
+            .unwrap()
+            .1
+            .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_in_query_call_implicit() {
+        assert!(InQueryCall::parse("CALL db.labels YIELD label").is_err());
+    }
+}
@@ -0,0 +1,150 @@
+use nom::{
+    character::complete::{digit1, multispace0, multispace1},
+    combinator::map,
+    sequence::{preceded, tuple},
+    IResult,
+};
+
+use crate::{
+    expression::Expression,
+    literal::string::String,
+    shared::{keyword, optional},
+    symbolic_name,
+};
+
+#[derive(Debug, PartialEq)]
+pub struct LoadCsv<'a> {
+    with_headers: bool,
+    url: Expression<'a>,
+    variable: &'a str,
+    field_terminator: Option<String<'a>>,
+}
+
+impl<'a> LoadCsv<'a> {
+    fn with_headers_str(&self) -> &str {
+        if self.with_headers {
+            return "WITH HEADERS ";
+        }
+        ""
+    }
+
+    fn field_terminator_str(&self) -> std::string::String {
+        self.field_terminator
+            .as_ref()
+            .map(|t| format!(" FIELDTERMINATOR {}", t.format()))
+            .unwrap_or_default()
+    }
+
+    pub fn format(&self) -> std::string::String {
+        format!(
+            "LOAD CSV {}FROM {} AS {}{}",
+            self.with_headers_str(),
+            self.url.format(),
+            self.variable,
+            self.field_terminator_str()
+        )
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                keyword("LOAD"),
+                multispace1,
+                keyword("CSV"),
+                multispace1,
+                optional(tuple((
+                    keyword("WITH"),
+                    multispace1,
+                    keyword("HEADERS"),
+                    multispace1,
+                ))),
+                keyword("FROM"),
+                multispace1,
+                Expression::parse,
+                multispace0,
+                keyword("AS"),
+                multispace1,
+                symbolic_name::parse,
+                optional(preceded(
+                    tuple((multispace1, keyword("FIELDTERMINATOR"), multispace1)),
+                    String::parse,
+                )),
+            )),
+            |(_, _, _, _, with_headers, _, _, url, _, _, _, variable, field_terminator)| LoadCsv {
+                with_headers: with_headers.is_some(),
+                url,
+                variable,
+                field_terminator,
+            },
+        )(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PeriodicCommit<'a>(Option<&'a str>);
+
+impl<'a> PeriodicCommit<'a> {
+    pub fn format(&self) -> std::string::String {
+        match self.0 {
+            Some(batch_size) => format!("USING PERIODIC COMMIT {}", batch_size),
+            None => "USING PERIODIC COMMIT".to_string(),
+        }
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                keyword("USING"),
+                multispace1,
+                keyword("PERIODIC"),
+                multispace1,
+                keyword("COMMIT"),
+                optional(preceded(multispace1, digit1)),
+            )),
+            |(_, _, _, _, _, batch_size)| PeriodicCommit(batch_size),
+        )(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_load_csv() {
+        let expected = Ok((
+            " data",
+            LoadCsv {
+                with_headers: false,
+                url: Expression::parameter("$url"),
+                variable: "line",
+                field_terminator: None,
+            },
+        ));
+        let actual = LoadCsv::parse("load csv from $url as line data");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_load_csv_with_headers() {
+        let expected = "LOAD CSV WITH HEADERS FROM 'file:///x.csv' AS row FIELDTERMINATOR ';'";
+        let actual =
+            LoadCsv::parse("load csv with headers from 'file:///x.csv' as row fieldterminator ';'")
+                .unwrap()
+                .1
+                .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_periodic_commit() {
+        assert_eq!(
+            Ok((" LOAD", PeriodicCommit(Some("500")))),
+            PeriodicCommit::parse("using periodic commit 500 LOAD")
+        );
+        assert_eq!(
+            Ok((" LOAD", PeriodicCommit(None))),
+            PeriodicCommit::parse("USING PERIODIC COMMIT LOAD")
+        );
+    }
+}
@@ -1,11 +1,22 @@
-use nom::{branch::alt, bytes::complete::tag, character::complete::space0, IResult};
+use nom::{
+    character::complete::{multispace0, multispace1},
+    sequence::{preceded, terminated, tuple},
+    IResult,
+};
 
-use crate::{pattern::Pattern, subclause::r#where::Where};
+use crate::{
+    format_options::FormatOptions,
+    pattern::{MatchMode, PatternPart},
+    shared,
+    subclause::{hint::Hint, r#where::Where},
+};
 
 #[derive(Debug, PartialEq)]
 pub struct Match<'a> {
     optional: bool,
-    patterns: Vec<Pattern<'a>>,
+    mode: Option<MatchMode>,
+    patterns: Vec<PatternPart<'a>>,
+    hints: Vec<Hint<'a>>,
     r#where: Option<Where<'a>>,
 }
 
@@ -17,29 +28,72 @@ impl<'a> Match<'a> {
         ""
     }
     pub fn format(&self) -> String {
+        self.format_with(&FormatOptions::default())
+    }
+
+    fn mode_str(&self) -> String {
+        self.mode
+            .as_ref()
+            .map(|m| format!(" {}", m.format()))
+            .unwrap_or_default()
+    }
+
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        format!(
+            "{}MATCH{}\n{}{}{}",
+            self.optional_str(),
+            self.mode_str(),
+            PatternPart::format_list(&self.patterns, options),
+            self.hints
+                .iter()
+                .map(|h| format!("\n{}", h.format()))
+                .collect::<String>(),
+            self.r#where
+                .as_ref()
+                .map(|w| format!("\n{}", w.format_with(options)))
+                .unwrap_or_default()
+        )
+    }
+
+    // The single-line form used inside `EXISTS { ... }` and friends.
+    pub fn format_inline(&self, options: &FormatOptions) -> String {
         format!(
-            "{}MATCH\n{}\n{}\n",
+            "{}MATCH{} {}{}{}",
             self.optional_str(),
+            self.mode_str(),
             self.patterns
                 .iter()
-                .map(|p| p.format())
+                .map(|p| p.format_with(options))
                 .collect::<Vec<_>>()
-                .join(" "),
-            self.r#where.as_ref().map(|w| w.format()).unwrap_or("".to_string())
+                .join(", "),
+            self.hints
+                .iter()
+                .map(|h| format!(" {}", h.format()))
+                .collect::<String>(),
+            self.r#where
+                .as_ref()
+                .map(|w| format!(" {}", w.format_with(options)))
+                .unwrap_or_default()
         )
     }
     pub fn parse(input: &'a str) -> IResult<&str, Self> {
-        let (input, keyword) = alt((tag("MATCH"), tag("OPTIONAL MATCH")))(input)?;
-        let optional = keyword == "OPTIONAL MATCH";
-        let (input, _) = space0(input)?;
-        let (input, pattern) = Pattern::parse(input)?;
+        let (input, optional) =
+            shared::optional(tuple((shared::keyword("OPTIONAL"), multispace1)))(input)?;
+        let (input, _) = shared::keyword("MATCH")(input)?;
+        let (input, _) = multispace0(input)?;
+        let (input, mode) = shared::optional(terminated(MatchMode::parse, multispace1))(input)?;
+        let (input, patterns) = PatternPart::parse_list(input)?;
+        let (input, hints) = Hint::parse_many(input)?;
+        let (input, r#where) = shared::optional(preceded(multispace0, Where::parse))(input)?;
 
         Ok((
             input,
             Match {
-                optional,
-                patterns: vec![pattern],
-                r#where: None,
+                optional: optional.is_some(),
+                mode,
+                patterns,
+                hints,
+                r#where,
             },
         ))
     }
@@ -47,7 +101,12 @@ impl<'a> Match<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{entity::Entity, label::Label, node::Node};
+    use crate::{
+        entity::Entity,
+        label::{Label, LabelExpression},
+        node::Node,
+        pattern::{AnonymousPatternPart, Pattern},
+    };
 
     use super::*;
 
@@ -57,11 +116,17 @@ mod tests {
             " data",
             Match {
                 optional: false,
-                patterns: vec![Pattern(vec![Entity::Node(Node {
-                    variable: Some("a"),
-                    properties: None,
-                    labels: vec![Label("ALabel")],
-                })])],
+                mode: None,
+                patterns: vec![PatternPart {
+                    variable: None,
+                    selector: None,
+                    pattern: AnonymousPatternPart::Pattern(Pattern(vec![Entity::Node(Node {
+                        variable: Some("a"),
+                        properties: None,
+                        labels: Some(LabelExpression::Label(Label("ALabel"))),
+                    })])),
+                }],
+                hints: vec![],
                 r#where: None,
             },
         ));
@@ -70,17 +135,81 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn format_match() {
+        let expected = "MATCH\n(a:ALabel)";
+        let actual = Match::parse("MATCH (a:ALabel)").unwrap().1.format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_match_where() {
+        let expected = "OPTIONAL MATCH\n(a:ALabel)\nWHERE a.age > 18";
+        let actual = Match::parse("OPTIONAL MATCH (a:ALabel)\n  where a.age>18")
+            .unwrap()
+            .1
+            .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_match_lowercase() {
+        assert_eq!("MATCH\n(n)", Match::parse("match (n)").unwrap().1.format());
+        assert_eq!(
+            "OPTIONAL MATCH\n(n)",
+            Match::parse("optional  match(n)").unwrap().1.format()
+        );
+    }
+
+    #[test]
+    fn format_match_patterns() {
+        let expected = "MATCH\np = (a) -[:KNOWS]-> (b), (c)";
+        let actual = Match::parse("MATCH p=(a)-[:KNOWS]->(b),(c)")
+            .unwrap()
+            .1
+            .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_match_shortest_path() {
+        let expected = "MATCH\np = shortestPath((a) -[:ROAD*..10]- (b))";
+        let actual = Match::parse("MATCH p = shortestPath((a)-[:ROAD*..10]-(b))")
+            .unwrap()
+            .1
+            .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_match_hints() {
+        let expected = "MATCH\n(p:Person) -[:KNOWS]-> (f:Person)\nUSING INDEX p:Person(name)\nUSING JOIN ON f\nWHERE p.name = $name";
+        let actual = Match::parse(
+            "MATCH (p:Person)-[:KNOWS]->(f:Person) using index p:Person(name) USING JOIN ON f WHERE p.name = $name",
+        )
+        .unwrap()
+        .1
+        .format();
+        assert_eq!(expected, actual);
+    }
+
     #[test]
     fn parse_match_optional() {
         let expected = Ok((
             " data",
             Match {
                 optional: true,
-                patterns: vec![Pattern(vec![Entity::Node(Node {
-                    variable: Some("a"),
-                    properties: None,
-                    labels: vec![Label("ALabel")],
-                })])],
+                mode: None,
+                patterns: vec![PatternPart {
+                    variable: None,
+                    selector: None,
+                    pattern: AnonymousPatternPart::Pattern(Pattern(vec![Entity::Node(Node {
+                        variable: Some("a"),
+                        properties: None,
+                        labels: Some(LabelExpression::Label(Label("ALabel"))),
+                    })])),
+                }],
+                hints: vec![],
                 r#where: None,
             },
         ));
@@ -0,0 +1,144 @@
+use nom::{
+    branch::alt,
+    character::complete::{multispace0, multispace1},
+    combinator::map,
+    multi::many0,
+    sequence::{preceded, tuple},
+    IResult,
+};
+
+use crate::{
+    clause::set::Set,
+    format_options::FormatOptions,
+    pattern::PatternPart,
+    shared::{indent, keyword},
+};
+
+#[derive(Debug, PartialEq)]
+pub enum MergeAction<'a> {
+    OnCreate(Set<'a>),
+    OnMatch(Set<'a>),
+}
+
+impl<'a> MergeAction<'a> {
+    pub fn format(&self) -> String {
+        match self {
+            MergeAction::OnCreate(set) => format!("ON CREATE {}", set.format()),
+            MergeAction::OnMatch(set) => format!("ON MATCH {}", set.format()),
+        }
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        let (input, _) = tuple((keyword("ON"), multispace1))(input)?;
+        alt((
+            map(
+                tuple((keyword("CREATE"), multispace1, Set::parse)),
+                |(_, _, set)| MergeAction::OnCreate(set),
+            ),
+            map(
+                tuple((keyword("MATCH"), multispace1, Set::parse)),
+                |(_, _, set)| MergeAction::OnMatch(set),
+            ),
+        ))(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Merge<'a> {
+    pattern: PatternPart<'a>,
+    actions: Vec<MergeAction<'a>>,
+}
+
+impl<'a> Merge<'a> {
+    fn actions_str(&self) -> String {
+        self.actions
+            .iter()
+            .map(|a| format!("\n{}", indent(&a.format())))
+            .collect()
+    }
+
+    pub fn format(&self) -> String {
+        self.format_with(&FormatOptions::default())
+    }
+
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        format!(
+            "MERGE {}{}",
+            self.pattern.format_with(options),
+            self.actions_str()
+        )
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                keyword("MERGE"),
+                multispace0,
+                PatternPart::parse,
+                many0(preceded(multispace0, MergeAction::parse)),
+            )),
+            |(_, _, pattern, actions)| Merge { pattern, actions },
+        )(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        clause::set::SetItem,
+        entity::Entity,
+        expression::Expression,
+        label::{Label, LabelExpression},
+        node::Node,
+        pattern::{AnonymousPatternPart, Pattern},
+        property_lookup::PropertyLookup,
+    };
+
+    use super::*;
+
+    #[test]
+    fn parse_merge_on_create() {
+        let expected = Ok((
+            "",
+            Merge {
+                pattern: PatternPart {
+                    variable: None,
+                    selector: None,
+                    pattern: AnonymousPatternPart::Pattern(Pattern(vec![Entity::Node(Node {
+                        variable: Some("n"),
+                        labels: Some(LabelExpression::Label(Label("Person"))),
+                        properties: None,
+                    })])),
+                },
+                actions: vec![MergeAction::OnCreate(Set {
+                    items: vec![SetItem::Property {
+                        variable: "n",
+                        property_lookups: vec![PropertyLookup("created")],
+                        expression: Expression::bool(true),
+                    }],
+                })],
+            },
+        ));
+        let actual = Merge::parse("merge (n:Person) on create set n.created = true");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_merge_actions() {
+        let expected = "MERGE (n:Person {id: $id})\n  ON CREATE SET n.created = $now\n  ON MATCH SET n.seen = $now, n.count = 1";
+        let actual = Merge::parse(
+            "MERGE (n:Person {id: $id}) ON CREATE SET n.created = $now ON MATCH SET n.seen = $now, n.count = 1",
+        )
+        .unwrap()
+        .1
+        .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_merge_named_path() {
+        let expected = "MERGE p = (a) -[:R]-> (b)";
+        let actual = Merge::parse("MERGE p = (a)-[:R]->(b)").unwrap().1.format();
+        assert_eq!(expected, actual);
+    }
+}
@@ -1,3 +1,14 @@
-pub mod r#match;
+pub mod create;
+pub mod delete;
+pub mod foreach;
 pub mod in_query_call;
+pub mod load_csv;
+pub mod r#match;
+pub mod merge;
+pub mod remove;
+pub mod set;
+pub mod standalone_call;
+pub mod subquery_call;
 pub mod unwind;
+pub mod r#use;
+pub mod with;
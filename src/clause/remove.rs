@@ -0,0 +1,135 @@
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{multispace0, multispace1, space0},
+    combinator::map,
+    multi::{many1, separated_list1},
+    sequence::tuple,
+    IResult,
+};
+
+use crate::{label::Label, property_lookup::PropertyLookup, shared::keyword, symbolic_name};
+
+#[derive(Debug, PartialEq)]
+pub enum RemoveItem<'a> {
+    Property {
+        variable: &'a str,
+        property_lookups: Vec<PropertyLookup<'a>>,
+    },
+    Labels {
+        variable: &'a str,
+        labels: Vec<Label<'a>>,
+    },
+}
+
+impl<'a> RemoveItem<'a> {
+    pub fn format(&self) -> String {
+        match self {
+            RemoveItem::Property {
+                variable,
+                property_lookups,
+            } => format!(
+                "{}{}",
+                variable,
+                property_lookups
+                    .iter()
+                    .map(|p| p.format())
+                    .collect::<String>()
+            ),
+            RemoveItem::Labels { variable, labels } => format!(
+                "{}{}",
+                variable,
+                labels
+                    .iter()
+                    .map(|l| format!(":{}", l.format()))
+                    .collect::<String>()
+            ),
+        }
+    }
+
+    fn parse_property(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((symbolic_name::parse, many1(PropertyLookup::parse))),
+            |(variable, property_lookups)| RemoveItem::Property {
+                variable,
+                property_lookups,
+            },
+        )(input)
+    }
+
+    fn parse_labels(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((symbolic_name::parse, Label::parse_many1)),
+            |(variable, labels)| RemoveItem::Labels { variable, labels },
+        )(input)
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        alt((Self::parse_property, Self::parse_labels))(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Remove<'a> {
+    items: Vec<RemoveItem<'a>>,
+}
+
+impl<'a> Remove<'a> {
+    pub fn format(&self) -> String {
+        format!(
+            "REMOVE {}",
+            self.items
+                .iter()
+                .map(|i| i.format())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                keyword("REMOVE"),
+                multispace1,
+                separated_list1(tuple((space0, tag(","), multispace0)), RemoveItem::parse),
+            )),
+            |(_, _, items)| Remove { items },
+        )(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_remove() {
+        let expected = Ok((
+            " data",
+            Remove {
+                items: vec![
+                    RemoveItem::Property {
+                        variable: "n",
+                        property_lookups: vec![PropertyLookup("age")],
+                    },
+                    RemoveItem::Labels {
+                        variable: "n",
+                        labels: vec![Label("Admin")],
+                    },
+                ],
+            },
+        ));
+        let actual = Remove::parse("remove n.age, n:Admin data");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_remove() {
+        let expected = "REMOVE n.age, n:Admin:Staff";
+        let actual = Remove::parse("REMOVE n.age ,n:Admin:Staff")
+            .unwrap()
+            .1
+            .format();
+        assert_eq!(expected, actual);
+    }
+}
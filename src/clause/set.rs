@@ -0,0 +1,259 @@
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{multispace0, multispace1, space0},
+    combinator::map,
+    multi::{many1, separated_list1},
+    sequence::tuple,
+    IResult,
+};
+
+use crate::{
+    expression::Expression,
+    label::Label,
+    property_lookup::PropertyLookup,
+    shared::{indent, keyword, MAX_LINE_WIDTH},
+    symbolic_name,
+};
+
+#[derive(Debug, PartialEq)]
+pub enum SetItem<'a> {
+    Property {
+        variable: &'a str,
+        property_lookups: Vec<PropertyLookup<'a>>,
+        expression: Expression<'a>,
+    },
+    Replace {
+        variable: &'a str,
+        expression: Expression<'a>,
+    },
+    Merge {
+        variable: &'a str,
+        expression: Expression<'a>,
+    },
+    Labels {
+        variable: &'a str,
+        labels: Vec<Label<'a>>,
+    },
+}
+
+impl<'a> SetItem<'a> {
+    pub fn format(&self) -> String {
+        match self {
+            SetItem::Property {
+                variable,
+                property_lookups,
+                expression,
+            } => format!(
+                "{}{} = {}",
+                variable,
+                property_lookups
+                    .iter()
+                    .map(|p| p.format())
+                    .collect::<String>(),
+                expression.format()
+            ),
+            SetItem::Replace {
+                variable,
+                expression,
+            } => format!("{} = {}", variable, expression.format()),
+            SetItem::Merge {
+                variable,
+                expression,
+            } => format!("{} += {}", variable, expression.format()),
+            SetItem::Labels { variable, labels } => format!(
+                "{}{}",
+                variable,
+                labels
+                    .iter()
+                    .map(|l| format!(":{}", l.format()))
+                    .collect::<String>()
+            ),
+        }
+    }
+
+    fn parse_property(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                symbolic_name::parse,
+                many1(PropertyLookup::parse),
+                space0,
+                tag("="),
+                space0,
+                Expression::parse,
+            )),
+            |(variable, property_lookups, _, _, _, expression)| SetItem::Property {
+                variable,
+                property_lookups,
+                expression,
+            },
+        )(input)
+    }
+
+    fn parse_replace(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                symbolic_name::parse,
+                space0,
+                tag("="),
+                space0,
+                Expression::parse,
+            )),
+            |(variable, _, _, _, expression)| SetItem::Replace {
+                variable,
+                expression,
+            },
+        )(input)
+    }
+
+    fn parse_merge(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                symbolic_name::parse,
+                space0,
+                tag("+="),
+                space0,
+                Expression::parse,
+            )),
+            |(variable, _, _, _, expression)| SetItem::Merge {
+                variable,
+                expression,
+            },
+        )(input)
+    }
+
+    fn parse_labels(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((symbolic_name::parse, Label::parse_many1)),
+            |(variable, labels)| SetItem::Labels { variable, labels },
+        )(input)
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        alt((
+            Self::parse_property,
+            Self::parse_replace,
+            Self::parse_merge,
+            Self::parse_labels,
+        ))(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Set<'a> {
+    pub items: Vec<SetItem<'a>>,
+}
+
+impl<'a> Set<'a> {
+    pub fn format(&self) -> String {
+        let items = self.items.iter().map(|i| i.format()).collect::<Vec<_>>();
+        let single_line = format!("SET {}", items.join(", "));
+        if single_line.len() <= MAX_LINE_WIDTH {
+            return single_line;
+        }
+
+        format!("SET\n{}", indent(&items.join(",\n")))
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                keyword("SET"),
+                multispace1,
+                separated_list1(tuple((space0, tag(","), multispace0)), SetItem::parse),
+            )),
+            |(_, _, items)| Set { items },
+        )(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{key_value::KeyValue, literal::map::Map};
+
+    use super::*;
+
+    #[test]
+    fn parse_set_property() {
+        let expected = Ok((
+            "data",
+            Set {
+                items: vec![SetItem::Property {
+                    variable: "n",
+                    property_lookups: vec![PropertyLookup("name")],
+                    expression: Expression::string("'Ann'"),
+                }],
+            },
+        ));
+        let actual = Set::parse("set n.name='Ann' data");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_set_properties() {
+        let expected = "SET n.name = 'Ann', n.age = 42";
+        let actual = Set::parse("SET n.name='Ann',n.age =  42")
+            .unwrap()
+            .1
+            .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_set_item_merge() {
+        let expected = Ok((
+            "",
+            SetItem::Merge {
+                variable: "n",
+                expression: Expression::map(Map(vec![KeyValue {
+                    key: "a",
+                    value: Expression::decimal_int("10"),
+                }])),
+            },
+        ));
+        let actual = SetItem::parse("n += {a: 10}");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_set_item_labels() {
+        let expected = Ok((
+            "",
+            SetItem::Labels {
+                variable: "n",
+                labels: vec![Label("Person"), Label("Admin")],
+            },
+        ));
+        let actual = SetItem::parse("n:Person:Admin");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_set_all_forms() {
+        let expected = "SET n = {name: 'Ann'}, m += {}, n:Person:Admin, n.age = 42";
+        let actual = Set::parse("SET n={name: 'Ann'}, m+={}, n :Person:Admin, n.age=42")
+            .unwrap()
+            .1
+            .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_set_expressions() {
+        let expected = "SET n = $props, m += row";
+        let actual = Set::parse("SET n=$props, m += row").unwrap().1.format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_set_long() {
+        let expected = "SET\n  n.firstName = $firstName,\n  n.lastName = $lastName,\n  n.email = $email,\n  n:Customer";
+        let actual = Set::parse(
+            "SET n.firstName = $firstName, n.lastName = $lastName, n.email = $email, n:Customer",
+        )
+        .unwrap()
+        .1
+        .format();
+        assert_eq!(expected, actual);
+    }
+}
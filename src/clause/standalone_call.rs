@@ -0,0 +1,78 @@
+use nom::{
+    character::complete::{multispace0, multispace1},
+    combinator::map,
+    sequence::{preceded, tuple},
+    IResult,
+};
+
+use crate::{
+    procedure_invocation::ProcedureInvocation,
+    shared::{keyword, optional},
+    subclause::r#yield::Yield,
+};
+
+#[derive(Debug, PartialEq)]
+pub struct StandaloneCall<'a> {
+    invocation: ProcedureInvocation<'a>,
+    r#yield: Option<Yield<'a>>,
+}
+
+impl<'a> StandaloneCall<'a> {
+    fn yield_str(&self) -> String {
+        self.r#yield
+            .as_ref()
+            .map(|y| format!(" {}", y.format()))
+            .unwrap_or_default()
+    }
+
+    pub fn format(&self) -> String {
+        format!(
+            "{}{}",
+            self.invocation.format_prefixed("CALL "),
+            self.yield_str()
+        )
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                keyword("CALL"),
+                multispace1,
+                ProcedureInvocation::parse,
+                optional(preceded(multispace0, Yield::parse)),
+            )),
+            |(_, _, invocation, r#yield)| StandaloneCall {
+                invocation,
+                r#yield,
+            },
+        )(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_standalone_call_implicit() {
+        let expected = Ok((
+            "",
+            StandaloneCall {
+                invocation: ProcedureInvocation::parse("db.awaitIndexes").unwrap().1,
+                r#yield: None,
+            },
+        ));
+        let actual = StandaloneCall::parse("CALL db.awaitIndexes");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_standalone_call_yield_all() {
+        let expected = "CALL db.schema.visualization() YIELD *";
+        let actual = StandaloneCall::parse("CALL db.schema.visualization()  YIELD  *")
+            .unwrap()
+            .1
+            .format();
+        assert_eq!(expected, actual);
+    }
+}
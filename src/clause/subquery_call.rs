@@ -0,0 +1,223 @@
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{multispace0, multispace1},
+    combinator::map,
+    multi::separated_list0,
+    sequence::{delimited, preceded, terminated, tuple},
+    IResult,
+};
+
+use crate::{
+    cypher::RegularQuery,
+    expression::Expression,
+    format_options::FormatOptions,
+    shared::{indent, keyword, optional},
+    symbolic_name,
+};
+
+#[derive(Debug, PartialEq)]
+pub enum OnError {
+    Continue,
+    Break,
+    Fail,
+}
+
+impl OnError {
+    pub fn format(&self) -> String {
+        match self {
+            OnError::Continue => "ON ERROR CONTINUE",
+            OnError::Break => "ON ERROR BREAK",
+            OnError::Fail => "ON ERROR FAIL",
+        }
+        .to_string()
+    }
+
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        preceded(
+            tuple((keyword("ON"), multispace1, keyword("ERROR"), multispace1)),
+            alt((
+                map(keyword("CONTINUE"), |_| OnError::Continue),
+                map(keyword("BREAK"), |_| OnError::Break),
+                map(keyword("FAIL"), |_| OnError::Fail),
+            )),
+        )(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct InTransactions<'a> {
+    rows: Option<Expression<'a>>,
+    on_error: Option<OnError>,
+    report_status: Option<&'a str>,
+}
+
+impl<'a> InTransactions<'a> {
+    pub fn format(&self) -> String {
+        let mut parts = vec!["IN TRANSACTIONS".to_string()];
+        if let Some(rows) = &self.rows {
+            parts.push(format!("OF {} ROWS", rows.format()));
+        }
+        if let Some(on_error) = &self.on_error {
+            parts.push(on_error.format());
+        }
+        if let Some(variable) = self.report_status {
+            parts.push(format!("REPORT STATUS AS {}", variable));
+        }
+        parts.join(" ")
+    }
+
+    fn parse_rows(input: &'a str) -> IResult<&str, Expression<'a>> {
+        delimited(
+            tuple((keyword("OF"), multispace1)),
+            Expression::parse,
+            tuple((multispace0, alt((keyword("ROWS"), keyword("ROW"))))),
+        )(input)
+    }
+
+    fn parse_report_status(input: &'a str) -> IResult<&str, &str> {
+        preceded(
+            tuple((
+                keyword("REPORT"),
+                multispace1,
+                keyword("STATUS"),
+                multispace1,
+                keyword("AS"),
+                multispace1,
+            )),
+            symbolic_name::parse,
+        )(input)
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                keyword("IN"),
+                multispace1,
+                keyword("TRANSACTIONS"),
+                optional(preceded(multispace1, Self::parse_rows)),
+                optional(preceded(multispace1, OnError::parse)),
+                optional(preceded(multispace1, Self::parse_report_status)),
+            )),
+            |(_, _, _, rows, on_error, report_status)| InTransactions {
+                rows,
+                on_error,
+                report_status,
+            },
+        )(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SubqueryCall<'a> {
+    scope: Option<Vec<&'a str>>,
+    query: RegularQuery<'a>,
+    in_transactions: Option<InTransactions<'a>>,
+}
+
+impl<'a> SubqueryCall<'a> {
+    fn scope_str(&self) -> String {
+        self.scope
+            .as_ref()
+            .map(|s| format!(" ({})", s.join(", ")))
+            .unwrap_or_default()
+    }
+
+    fn in_transactions_str(&self) -> String {
+        self.in_transactions
+            .as_ref()
+            .map(|t| format!(" {}", t.format()))
+            .unwrap_or_default()
+    }
+
+    pub fn format(&self) -> String {
+        self.format_with(&FormatOptions::default())
+    }
+
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        format!(
+            "CALL{} {{\n{}\n}}{}",
+            self.scope_str(),
+            indent(&self.query.format_with(options)),
+            self.in_transactions_str()
+        )
+    }
+
+    fn parse_scope(input: &'a str) -> IResult<&str, Vec<&'a str>> {
+        delimited(
+            tuple((tag("("), multispace0)),
+            alt((
+                map(tag("*"), |wild_card| vec![wild_card]),
+                separated_list0(
+                    tuple((multispace0, tag(","), multispace0)),
+                    symbolic_name::parse,
+                ),
+            )),
+            tuple((multispace0, tag(")"))),
+        )(input)
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                keyword("CALL"),
+                multispace0,
+                optional(terminated(Self::parse_scope, multispace0)),
+                delimited(
+                    tuple((tag("{"), multispace0)),
+                    RegularQuery::parse,
+                    tuple((multispace0, tag("}"))),
+                ),
+                optional(preceded(multispace0, InTransactions::parse)),
+            )),
+            |(_, _, scope, query, in_transactions)| SubqueryCall {
+                scope,
+                query,
+                in_transactions,
+            },
+        )(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_subquery_call() {
+        let expected = "CALL {\n  WITH p\n  MATCH\n  (p) -[:KNOWS]-> (f)\n  RETURN f\n}";
+        let actual = SubqueryCall::parse("call { with p MATCH (p)-[:KNOWS]->(f) return f }")
+            .unwrap()
+            .1
+            .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_subquery_call_scope_in_transactions() {
+        let expected = "CALL (row) {\n  CREATE\n  (:Person {name: row.name})\n} IN TRANSACTIONS OF 1000 ROWS ON ERROR CONTINUE";
+        let actual = SubqueryCall::parse(
+            "CALL(row){CREATE (:Person {name: row.name})} in transactions of 1000 rows on error continue",
+        )
+        .unwrap()
+        .1
+        .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_in_transactions() {
+        let expected = Ok((
+            " data",
+            InTransactions {
+                rows: Some(Expression::parameter("$batch")),
+                on_error: Some(OnError::Break),
+                report_status: Some("status"),
+            },
+        ));
+        let actual = InTransactions::parse(
+            "IN TRANSACTIONS OF $batch ROWS ON ERROR BREAK REPORT STATUS AS status data",
+        );
+        assert_eq!(expected, actual);
+    }
+}
@@ -1,7 +1,71 @@
-use crate::expression::Expression;
+use nom::{
+    character::complete::{multispace0, multispace1},
+    combinator::map,
+    sequence::tuple,
+    IResult,
+};
+
+use crate::{expression::Expression, shared::keyword, symbolic_name};
 
 #[derive(Debug, PartialEq)]
 pub struct Unwind<'a> {
     expressions: Vec<Expression<'a>>,
     variable: &'a str,
 }
+
+impl<'a> Unwind<'a> {
+    pub fn format(&self) -> String {
+        format!(
+            "UNWIND {} AS {}",
+            self.expressions
+                .iter()
+                .map(|e| e.format())
+                .collect::<Vec<_>>()
+                .join(" "),
+            self.variable
+        )
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                keyword("UNWIND"),
+                multispace1,
+                Expression::parse,
+                multispace0,
+                keyword("AS"),
+                multispace1,
+                symbolic_name::parse,
+            )),
+            |(_, _, expression, _, _, _, variable)| Unwind {
+                expressions: vec![expression],
+                variable,
+            },
+        )(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_unwind() {
+        let expected = Ok((
+            " data",
+            Unwind {
+                expressions: vec![Expression::parameter("$rows")],
+                variable: "row",
+            },
+        ));
+        let actual = Unwind::parse("unwind $rows as row data");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_unwind() {
+        let expected = "UNWIND [1, 2, 3] AS x";
+        let actual = Unwind::parse("UNWIND [1,2,3]   AS x").unwrap().1.format();
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,54 @@
+use nom::{character::complete::multispace1, combinator::map, sequence::preceded, IResult};
+
+use crate::{procedure_invocation::ProcedureInvocation, shared::keyword};
+
+// The graph reference is either a (possibly dotted) name or a graph function
+// call such as `graph.byName($name)`, which has the same shape as a procedure
+// invocation.
+#[derive(Debug, PartialEq)]
+pub struct Use<'a> {
+    target: ProcedureInvocation<'a>,
+}
+
+impl<'a> Use<'a> {
+    pub fn format(&self) -> String {
+        self.target.format_prefixed("USE ")
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            preceded(
+                preceded(keyword("USE"), multispace1),
+                ProcedureInvocation::parse,
+            ),
+            |target| Use { target },
+        )(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_use() {
+        assert_eq!("USE movies", Use::parse("use movies").unwrap().1.format());
+        assert_eq!(
+            "USE graph.byName($name)",
+            Use::parse("USE graph.byName( $name )").unwrap().1.format()
+        );
+    }
+
+    #[test]
+    fn format_use_wrapped_at_prefix() {
+        assert_eq!(
+            "USE graph.byElementId(\n  '4:c0a65d96-4993-4b0c-b036-e7ebd9174905:0',\n  $fallbackGraph\n)",
+            Use::parse(
+                "USE graph.byElementId('4:c0a65d96-4993-4b0c-b036-e7ebd9174905:0', $fallbackGraph)"
+            )
+            .unwrap()
+            .1
+            .format()
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use nom::{
+    character::complete::{multispace0, multispace1},
+    combinator::map,
+    sequence::{preceded, tuple},
+    IResult,
+};
+
+use crate::{
+    format_options::FormatOptions,
+    projection_body::ProjectionBody,
+    shared::{keyword, optional},
+    subclause::r#where::Where,
+};
+
+#[derive(Debug, PartialEq)]
+pub struct With<'a> {
+    projection_body: ProjectionBody<'a>,
+    r#where: Option<Where<'a>>,
+}
+
+impl<'a> With<'a> {
+    fn where_str(&self, options: &FormatOptions) -> String {
+        self.r#where
+            .as_ref()
+            .map(|w| format!("\n{}", w.format_with(options)))
+            .unwrap_or_default()
+    }
+
+    pub fn format(&self) -> String {
+        self.format_with(&FormatOptions::default())
+    }
+
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        format!(
+            "WITH {}{}",
+            self.projection_body.format_at(options, "WITH ".len()),
+            self.where_str(options)
+        )
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                keyword("WITH"),
+                multispace1,
+                ProjectionBody::parse,
+                optional(preceded(multispace0, Where::parse)),
+            )),
+            |(_, _, projection_body, r#where)| With {
+                projection_body,
+                r#where,
+            },
+        )(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{expression::Expression, projection_body::ProjectionItem};
+
+    use super::*;
+
+    #[test]
+    fn parse_with() {
+        let expected = Ok((
+            " data",
+            With {
+                projection_body: ProjectionBody {
+                    distinct: false,
+                    wild_card: false,
+                    projection_items: vec![ProjectionItem {
+                        expression: Expression::variable("n"),
+                        variable: Some("m"),
+                    }],
+                    sort_expressions: vec![],
+                    skip_expression: None,
+                    limit_expression: None,
+                },
+                r#where: None,
+            },
+        ));
+        let actual = With::parse("with n AS m data");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_with_where() {
+        let expected = "WITH DISTINCT n, n.age AS age\nWHERE age > 18";
+        let actual = With::parse("WITH   distinct n,n.age AS age WHERE age>18")
+            .unwrap()
+            .1
+            .format();
+        assert_eq!(expected, actual);
+    }
+}
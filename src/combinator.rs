@@ -1,4 +1,6 @@
-use nom::{branch::alt, bytes::complete::tag_no_case, combinator::map, IResult};
+use nom::{branch::alt, combinator::map, IResult};
+
+use crate::shared::keyword;
 
 #[derive(Debug, PartialEq)]
 pub enum Combinator {
@@ -8,16 +10,25 @@ pub enum Combinator {
 }
 
 impl Combinator {
+    pub fn format(&self) -> String {
+        match self {
+            Combinator::Or => "OR",
+            Combinator::And => "AND",
+            Combinator::Xor => "XOR",
+        }
+        .to_string()
+    }
+
     fn parse_or(input: &str) -> IResult<&str, Self> {
-        map(tag_no_case("OR"), |_| Combinator::Or)(input)
+        map(keyword("OR"), |_| Combinator::Or)(input)
     }
 
     fn parse_and(input: &str) -> IResult<&str, Self> {
-        map(tag_no_case("AND"), |_| Combinator::And)(input)
+        map(keyword("AND"), |_| Combinator::And)(input)
     }
 
     fn parse_xor(input: &str) -> IResult<&str, Self> {
-        map(tag_no_case("XOR"), |_| Combinator::Xor)(input)
+        map(keyword("XOR"), |_| Combinator::Xor)(input)
     }
 
     pub fn parse(input: &str) -> IResult<&str, Self> {
@@ -43,6 +54,11 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn parse_combinator_prefix_of_word() {
+        assert!(Combinator::parse("order by").is_err());
+    }
+
     #[test]
     fn parse_combinator_xor() {
         let expected = Ok((" data", Combinator::Xor));
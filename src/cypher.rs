@@ -1,30 +1,105 @@
 use nom::{
     branch::alt,
-    bytes::complete::{tag, tag_no_case},
-    character::complete::{space0, space1},
-    combinator::{map, recognize},
-    multi::many1,
-    sequence::tuple,
+    bytes::complete::tag,
+    character::complete::{multispace0, multispace1},
+    combinator::{map, recognize, verify},
+    multi::{many0, many1},
+    sequence::{terminated, tuple},
     IResult,
 };
 
-use crate::{shared::optional, single_part_query::SinglePartQuery};
+use crate::{
+    administration_command::AdministrationCommand,
+    clause::{load_csv::PeriodicCommit, r#use::Use, standalone_call::StandaloneCall, with::With},
+    format_options::FormatOptions,
+    query_prefix::QueryPrefix,
+    schema_command::SchemaCommand,
+    shared::{keyword, optional},
+    single_part_query::{
+        parse_read_parts, parse_updating_parts, ReadingClause, SinglePartQuery, UpdatingClause,
+    },
+};
+
+#[derive(Debug, PartialEq)]
+pub struct MultiPart<'a> {
+    read_parts: Vec<ReadingClause<'a>>,
+    updating_parts: Vec<UpdatingClause<'a>>,
+    with: With<'a>,
+}
+
+impl<'a> MultiPart<'a> {
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        self.read_parts
+            .iter()
+            .map(|r| r.format_with(options))
+            .chain(self.updating_parts.iter().map(|u| u.format_with(options)))
+            .chain(std::iter::once(self.with.format_with(options)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                parse_read_parts,
+                parse_updating_parts,
+                With::parse,
+                multispace0,
+            )),
+            |(read_parts, updating_parts, with, _)| MultiPart {
+                read_parts,
+                updating_parts,
+                with,
+            },
+        )(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MultiPartQuery<'a> {
+    parts: Vec<MultiPart<'a>>,
+    single_part_query: SinglePartQuery<'a>,
+}
+
+impl<'a> MultiPartQuery<'a> {
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        self.parts
+            .iter()
+            .map(|p| p.format_with(options))
+            .chain(std::iter::once(self.single_part_query.format_with(options)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((many1(MultiPart::parse), SinglePartQuery::parse)),
+            |(parts, single_part_query)| MultiPartQuery {
+                parts,
+                single_part_query,
+            },
+        )(input)
+    }
+}
 
 #[derive(Debug, PartialEq)]
 pub enum PartQuery<'a> {
     Single(SinglePartQuery<'a>),
-    Multi,
+    Multi(MultiPartQuery<'a>),
 }
 
 impl<'a> PartQuery<'a> {
     pub fn parse(input: &'a str) -> IResult<&str, Self> {
-        map(SinglePartQuery::parse, PartQuery::Single)(input)
+        alt((
+            map(MultiPartQuery::parse, PartQuery::Multi),
+            map(SinglePartQuery::parse, PartQuery::Single),
+        ))(input)
     }
 
-    pub fn format(&self) -> String {
+    pub fn format_with(&self, options: &FormatOptions) -> String {
         match self {
-            PartQuery::Single(single) => single.format(),
-            _ => "".to_string(),
+            PartQuery::Single(single) => single.format_with(options),
+            PartQuery::Multi(multi) => multi.format_with(options),
         }
     }
 }
@@ -32,45 +107,159 @@ impl<'a> PartQuery<'a> {
 #[derive(Debug, PartialEq)]
 pub struct CombinablePartQuery<'a> {
     combinator: Option<&'a str>,
+    r#use: Option<Use<'a>>,
     part_query: PartQuery<'a>,
 }
 
 fn parse_union(input: &str) -> IResult<&str, &str> {
-    tag_no_case("UNION")(input)
+    keyword("UNION")(input)
 }
 
 fn parse_union_all(input: &str) -> IResult<&str, &str> {
-    recognize(tuple((parse_union, space1, tag_no_case("ALL"))))(input)
+    recognize(tuple((parse_union, multispace1, keyword("ALL"))))(input)
 }
 
 impl<'a> CombinablePartQuery<'a> {
+    fn combinator_str(&self) -> Option<String> {
+        self.combinator.map(|c| {
+            c.split_whitespace()
+                .map(|word| word.to_uppercase())
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+    }
+
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        self.combinator_str()
+            .into_iter()
+            .chain(self.r#use.iter().map(|u| u.format()))
+            .chain(std::iter::once(self.part_query.format_with(options)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    // Each part of a union may target its own graph with a leading `USE`.
+    fn parse_part(input: &'a str) -> IResult<&str, (Option<Use<'a>>, PartQuery<'a>)> {
+        tuple((
+            optional(terminated(Use::parse, multispace1)),
+            PartQuery::parse,
+        ))(input)
+    }
+
+    fn parse_first(input: &'a str) -> IResult<&str, Self> {
+        map(Self::parse_part, |(r#use, part_query)| {
+            CombinablePartQuery {
+                combinator: None,
+                r#use,
+                part_query,
+            }
+        })(input)
+    }
+
     pub fn parse(input: &'a str) -> IResult<&str, Self> {
         map(
             tuple((
-                optional(alt((parse_union_all, parse_union))),
-                PartQuery::parse,
+                multispace0,
+                alt((parse_union_all, parse_union)),
+                multispace1,
+                Self::parse_part,
             )),
-            |(combinator, part_query)| CombinablePartQuery {
-                combinator,
+            |(_, combinator, _, (r#use, part_query))| CombinablePartQuery {
+                combinator: Some(combinator),
+                r#use,
                 part_query,
             },
         )(input)
     }
 }
 
+fn is_single_union_kind(queries: &[CombinablePartQuery]) -> bool {
+    let mut combinators = queries.iter().filter_map(|q| q.combinator_str());
+    match combinators.next() {
+        Some(first) => combinators.all(|c| c == first),
+        None => true,
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RegularQuery<'a> {
+    queries: Vec<CombinablePartQuery<'a>>,
+}
+
+impl<'a> RegularQuery<'a> {
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        self.queries
+            .iter()
+            .map(|q| q.format_with(options))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        let (input, first) = CombinablePartQuery::parse_first(input)?;
+        let (input, rest) = verify(many0(CombinablePartQuery::parse), |rest: &[_]| {
+            is_single_union_kind(rest)
+        })(input)?;
+
+        Ok((
+            input,
+            RegularQuery {
+                queries: std::iter::once(first).chain(rest).collect(),
+            },
+        ))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Query<'a> {
+    Regular(RegularQuery<'a>),
+    StandaloneCall(StandaloneCall<'a>),
+    Schema(SchemaCommand<'a>),
+    Administration(AdministrationCommand<'a>),
+}
+
+impl<'a> Query<'a> {
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        match self {
+            Query::Regular(regular) => regular.format_with(options),
+            Query::StandaloneCall(call) => call.format(),
+            Query::Schema(command) => command.format(),
+            Query::Administration(command) => command.format(),
+        }
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        alt((
+            map(SchemaCommand::parse, Query::Schema),
+            map(AdministrationCommand::parse, Query::Administration),
+            map(RegularQuery::parse, Query::Regular),
+            map(StandaloneCall::parse, Query::StandaloneCall),
+        ))(input)
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub struct Cypher<'a> {
-    queries: Vec<PartQuery<'a>>,
+    prefixes: Vec<QueryPrefix<'a>>,
+    periodic_commit: Option<PeriodicCommit<'a>>,
+    query: Query<'a>,
     semicolon: bool,
 }
 
 impl<'a> Cypher<'a> {
     pub fn parse(query: &'a str) -> IResult<&str, Self> {
-        let (query, _) = space0(query)?;
+        let (query, _) = multispace0(query)?;
         map(
-            tuple((many1(PartQuery::parse), optional(tag(";")))),
-            |(queries, semicolon)| Cypher {
-                queries,
+            tuple((
+                many0(terminated(QueryPrefix::parse, multispace1)),
+                optional(terminated(PeriodicCommit::parse, multispace1)),
+                Query::parse,
+                optional(tuple((multispace0, tag(";")))),
+            )),
+            |(prefixes, periodic_commit, query, semicolon)| Cypher {
+                prefixes,
+                periodic_commit,
+                query,
                 semicolon: semicolon.is_some(),
             },
         )(query)
@@ -85,9 +274,36 @@ impl<'a> Cypher<'a> {
     }
 
     pub fn format(&self) -> String {
+        self.format_with(&FormatOptions::default())
+    }
+
+    fn prefixes_str(&self) -> String {
+        if self.prefixes.is_empty() {
+            return "".to_string();
+        }
         format!(
-            "{}{}",
-            self.queries.iter().next().unwrap().format(),
+            "{}\n",
+            self.prefixes
+                .iter()
+                .map(|p| p.format())
+                .collect::<Vec<_>>()
+                .join(" ")
+        )
+    }
+
+    fn periodic_commit_str(&self) -> String {
+        self.periodic_commit
+            .as_ref()
+            .map(|p| format!("{}\n", p.format()))
+            .unwrap_or_default()
+    }
+
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        format!(
+            "{}{}{}{}",
+            self.prefixes_str(),
+            self.periodic_commit_str(),
+            self.query.format_with(options),
             self.semi_colon_str()
         )
     }
@@ -96,6 +312,371 @@ impl<'a> Cypher<'a> {
 #[cfg(test)]
 mod tests {
 
+    use crate::format_options::RelationshipBrackets;
+
+    use super::*;
+
+    #[test]
+    fn format_multi_part_query() {
+        let expected = "WITH $people AS people, 30 AS age\nWHERE age > 18\nWITH DISTINCT people, age\nRETURN people, age;";
+        let actual = Cypher::parse(
+            "with $people AS people,30 as age WHERE age>18 WITH distinct people, age RETURN people,age ;",
+        )
+        .unwrap()
+        .1
+        .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_sort_skip_limit() {
+        let expected = "WITH n\nORDER BY n.age ASCENDING\nLIMIT 3\nRETURN n.name, n.age\nORDER BY n.age DESC, n.name\nSKIP 10\nLIMIT 5";
+        let actual = Cypher::parse(
+            "WITH n order by n.age ascending limit 3 RETURN n.name, n.age ORDER BY n.age DESC,n.name SKIP 10 LIMIT 5",
+        )
+        .unwrap()
+        .1
+        .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_part_query_multi() {
+        let actual = PartQuery::parse("WITH 1 AS a\nWITH a RETURN a data").unwrap();
+        match actual {
+            ("data", PartQuery::Multi(multi)) => assert_eq!(multi.parts.len(), 2),
+            other => panic!("unexpected parse result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn format_union() {
+        let expected =
+            "RETURN 1 AS a\nUNION ALL\nRETURN 2 AS a\nUNION ALL\nWITH 3 AS b\nRETURN b AS a;";
+        let actual = Cypher::parse(
+            "RETURN 1 AS a union  all RETURN 2 AS a\nUNION ALL WITH 3 AS b RETURN b AS a;",
+        )
+        .unwrap()
+        .1
+        .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_create_query() {
+        let expected =
+            "CREATE\n(a:Person {name: $name})\nWITH a\nCREATE\n(a) -[:OWNS]-> (:Pet)\nRETURN a;";
+        let actual = Cypher::parse(
+            "CREATE (a:Person {name: $name}) WITH a CREATE (a)-[:OWNS]->(:Pet) RETURN a;",
+        )
+        .unwrap()
+        .1
+        .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_create_without_return() {
+        let expected = "CREATE\n(a), (b)";
+        let actual = Cypher::parse("CREATE (a), (b)").unwrap().1.format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_updating_clauses() {
+        let expected = "MERGE (n:Tag {id: 1})\nSET n:Active\nREMOVE n.old\nWITH n\nDETACH DELETE n";
+        let actual =
+            Cypher::parse("MERGE (n:Tag {id: 1}) SET n:Active REMOVE n.old WITH n DETACH DELETE n")
+                .unwrap()
+                .1
+                .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_unwind_query() {
+        let expected = "UNWIND $rows AS row\nMERGE (p:Person {id: row.id})\nSET p.name = row.name";
+        let actual = Cypher::parse(
+            "UNWIND $rows AS row MERGE (p:Person {id: row.id}) SET p.name = row.name",
+        )
+        .unwrap()
+        .1
+        .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_in_query_call() {
+        let expected = "CALL db.index.fulltext.queryNodes('people', $q) YIELD node, score\nWHERE score > 0.5\nRETURN node.name, score";
+        let actual = Cypher::parse(
+            "CALL db.index.fulltext.queryNodes('people', $q) YIELD node, score WHERE score > 0.5 RETURN node.name, score",
+        )
+        .unwrap()
+        .1
+        .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_standalone_call() {
+        let expected = "CALL db.labels YIELD label;";
+        let actual = Cypher::parse("call db.labels yield label ;")
+            .unwrap()
+            .1
+            .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_match_where_query() {
+        let expected = "MATCH\n(n:Person)\nWHERE n.age >= 18\nRETURN n.name";
+        let actual = Cypher::parse("MATCH (n:Person) where n.age >= 18 RETURN n.name")
+            .unwrap()
+            .1
+            .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_bracketless_relationships() {
+        let query = Cypher::parse("MATCH (a)-->(b)<-[]-(c) RETURN a").unwrap().1;
+        let options = FormatOptions {
+            relationship_brackets: RelationshipBrackets::Collapse,
+            ..Default::default()
+        };
+        assert_eq!("MATCH\n(a) --> (b) <-[]- (c)\nRETURN a", query.format());
+        assert_eq!(
+            "MATCH\n(a) --> (b) <-- (c)\nRETURN a",
+            query.format_with(&options)
+        );
+    }
+
+    #[test]
+    fn format_shortest_path_expression() {
+        let expected =
+            "MATCH\n(a:City), (b:City)\nRETURN allShortestPaths((a) -[:ROAD*]- (b)) AS routes";
+        let actual = Cypher::parse(
+            "MATCH (a:City),(b:City) RETURN allShortestPaths((a)-[:ROAD*]-(b)) AS routes",
+        )
+        .unwrap()
+        .1
+        .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_load_csv_query() {
+        let expected = "USING PERIODIC COMMIT 500\nLOAD CSV WITH HEADERS FROM 'file:///people.csv' AS row FIELDTERMINATOR ';'\nCREATE\n(:Person {name: row.name});";
+        let actual = Cypher::parse(
+            "using periodic commit 500 load csv with headers from 'file:///people.csv' as row fieldterminator ';' create (:Person {name: row.name});",
+        )
+        .unwrap()
+        .1
+        .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_subquery_union() {
+        let expected = "MATCH\n(p:Person)\nCALL {\n  WITH p\n  MATCH\n  (p) -[:KNOWS]-> (f)\n  RETURN f AS other\n  UNION\n  WITH p\n  MATCH\n  (p) <-[:KNOWS]- (f)\n  RETURN f AS other\n}\nRETURN p.name, other.name";
+        let actual = Cypher::parse(
+            "MATCH (p:Person) CALL { WITH p MATCH (p)-[:KNOWS]->(f) RETURN f AS other UNION WITH p MATCH (p)<-[:KNOWS]-(f) RETURN f AS other } RETURN p.name, other.name",
+        )
+        .unwrap()
+        .1
+        .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_reading_clauses_after_subquery() {
+        let expected = "MATCH\n(a)\nCALL {\n  WITH a\n  MATCH\n  (a) --> (b)\n  RETURN b\n}\nMATCH\n(b) --> (c)\nRETURN c";
+        let actual = Cypher::parse(
+            "MATCH (a) CALL { WITH a MATCH (a)-->(b) RETURN b } MATCH (b)-->(c) RETURN c",
+        )
+        .unwrap()
+        .1
+        .format();
+        assert_eq!(expected, actual);
+
+        let expected =
+            "CALL {\n  MATCH\n  (n)\n  RETURN n.tags AS tags\n}\nUNWIND tags AS tag\nRETURN tag";
+        let actual =
+            Cypher::parse("CALL { MATCH (n) RETURN n.tags AS tags } UNWIND tags AS tag RETURN tag")
+                .unwrap()
+                .1
+                .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_subquery_expressions() {
+        let expected = "MATCH\n(p:Person)\nWHERE EXISTS { (p) -[:HAS_DOG]-> (:Dog) }\nRETURN p.name, COUNT { (p) --> () } AS degree";
+        let actual = Cypher::parse(
+            "MATCH (p:Person) WHERE exists {(p)-[:HAS_DOG]->(:Dog)} RETURN p.name, count { (p)-->() } AS degree",
+        )
+        .unwrap()
+        .1
+        .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_subquery_expression_with_prefix() {
+        let expected = "MATCH\n(person:Person)\nWHERE EXISTS {\n  (person) -[:HAS_DOG]-> (dog:Dog)\n  WHERE dog.name = 'Ozzy' AND dog.age > 3\n}\nRETURN person";
+        let actual = Cypher::parse(
+            "MATCH (person:Person) WHERE EXISTS { (person)-[:HAS_DOG]->(dog:Dog) WHERE dog.name = 'Ozzy' AND dog.age > 3 } RETURN person",
+        )
+        .unwrap()
+        .1
+        .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_subquery_expression_options() {
+        let options = FormatOptions {
+            relationship_brackets: RelationshipBrackets::Expand,
+            normalize_label_conjunction: true,
+            ..Default::default()
+        };
+        let expected =
+            "MATCH\n(a)\nWHERE EXISTS { (a) -[]-> (:B&C) }\nRETURN shortestPath((a) -[]- (b))";
+        let actual =
+            Cypher::parse("MATCH (a) WHERE EXISTS { (a)-->(:B:C) } RETURN shortestPath((a)--(b))")
+                .unwrap()
+                .1
+                .format_with(&options);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_schema_command() {
+        let expected = "CREATE RANGE INDEX person_name IF NOT EXISTS FOR (p:Person) ON (p.name);";
+        let actual = Cypher::parse(
+            "CREATE RANGE INDEX person_name IF NOT EXISTS\nFOR (p:Person)\nON (p.name);",
+        )
+        .unwrap()
+        .1
+        .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_administration_command() {
+        let expected = "SHOW INDEXES YIELD name, state\nWHERE state <> 'ONLINE';";
+        let actual = Cypher::parse("show indexes yield name, state where state <> 'ONLINE';")
+            .unwrap()
+            .1
+            .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_query_prefixes() {
+        let expected = "CYPHER 5 runtime=parallel EXPLAIN\nMATCH\n(n:Person)\nRETURN n";
+        let actual = Cypher::parse("cypher 5 runtime=parallel\nexplain MATCH (n:Person) RETURN n")
+            .unwrap()
+            .1
+            .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_use_clause() {
+        let expected = "USE fabric.sales\nCALL {\n  USE graph.byName($name)\n  MATCH\n  (o:Order)\n  RETURN o\n}\nRETURN count";
+        let actual = Cypher::parse(
+            "use fabric.sales CALL { USE graph.byName($name) MATCH (o:Order) RETURN o } RETURN count",
+        )
+        .unwrap()
+        .1
+        .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_use_clause_per_union_part() {
+        let expected = "USE g1\nMATCH\n(n)\nRETURN n\nUNION\nUSE g2\nMATCH\n(n)\nRETURN n";
+        let actual = Cypher::parse("USE g1 MATCH (n) RETURN n UNION USE g2 MATCH (n) RETURN n")
+            .unwrap()
+            .1
+            .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_quantified_path_pattern() {
+        let expected = "MATCH REPEATABLE ELEMENTS\np = ANY SHORTEST (a:Station) ((x) -[:LINK]-> (y) WHERE x.open){1,4} (b:Station)\nRETURN p";
+        let actual = Cypher::parse(
+            "MATCH repeatable elements p = any shortest (a:Station)((x)-[:LINK]->(y) WHERE x.open){1,4}(b:Station) RETURN p",
+        )
+        .unwrap()
+        .1
+        .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_label_expressions() {
+        let expected = "MATCH\n(n:(Person|Robot)&!Retired) -[:KNOWS|LIKES]-> (m:%)\nWHERE m:Admin|Owner\nRETURN n";
+        let actual = Cypher::parse(
+            "MATCH (n:(Person|Robot)&!Retired)-[:KNOWS|:LIKES]->(m:%) WHERE m:Admin|Owner RETURN n",
+        )
+        .unwrap()
+        .1
+        .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_normalized_label_conjunction() {
+        let options = FormatOptions {
+            normalize_label_conjunction: true,
+            ..Default::default()
+        };
+        let expected = "MATCH\n(n:Person&Admin) -[:KNOWS]-> (m)\nRETURN n";
+        let actual = Cypher::parse("MATCH (n:Person:Admin)-[:KNOWS]->(m) RETURN n")
+            .unwrap()
+            .1
+            .format_with(&options);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_normalized_label_predicate() {
+        let options = FormatOptions {
+            normalize_label_conjunction: true,
+            ..Default::default()
+        };
+        let expected = "MATCH\n(n)\nWHERE n:Person&Admin\nRETURN n:Person&Admin AS admin";
+        let actual = Cypher::parse("MATCH (n) WHERE n:Person:Admin RETURN n:Person:Admin AS admin")
+            .unwrap()
+            .1
+            .format_with(&options);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_quantified_path_where_options() {
+        let options = FormatOptions {
+            relationship_brackets: RelationshipBrackets::Collapse,
+            normalize_label_conjunction: true,
+            ..Default::default()
+        };
+        let expected = "MATCH\n((a) --> (b) WHERE EXISTS { (a) --> (:X&Y) })+\nRETURN a";
+        let actual = Cypher::parse("MATCH ((a)-[]->(b) WHERE EXISTS { (a)-[]->(:X:Y) })+ RETURN a")
+            .unwrap()
+            .1
+            .format_with(&options);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_union_mixed_with_union_all() {
+        let actual = Cypher::parse("RETURN 1 AS a UNION RETURN 2 AS a UNION ALL RETURN 3 AS a");
+        assert!(actual.is_err());
+    }
+
     #[test]
     fn parse_union() {
         let expected = Ok((" data", "union"));
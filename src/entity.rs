@@ -1,21 +1,33 @@
-use nom::{
-    combinator::{map, opt},
-    IResult,
-};
+use nom::{combinator::opt, IResult};
 
-use crate::{node::Node, relationship::Relationship};
+use crate::{
+    format_options::FormatOptions,
+    node::Node,
+    pattern::{PathSegment, Quantifier},
+    relationship::Relationship,
+};
 
 #[derive(Debug, PartialEq)]
 pub enum Entity<'a> {
     Node(Node<'a>),
     Relationship(Relationship<'a>),
+    QuantifiedRelationship(Relationship<'a>, Quantifier<'a>),
+    Segment(PathSegment<'a>),
 }
 
 impl<'a> Entity<'a> {
     pub fn format(&self) -> String {
+        self.format_with(&FormatOptions::default())
+    }
+
+    pub fn format_with(&self, options: &FormatOptions) -> String {
         match self {
-            Entity::Node(n) => n.format(),
-            Entity::Relationship(r) => r.format(),
+            Entity::Node(n) => n.format_with(options),
+            Entity::Relationship(r) => r.format_with(options),
+            Entity::QuantifiedRelationship(r, q) => {
+                format!("{}{}", r.format_with(options), q.format())
+            }
+            Entity::Segment(s) => s.format_with(options),
         }
     }
     pub fn parse(input: &'a str) -> IResult<&str, Self> {
@@ -24,7 +36,18 @@ impl<'a> Entity<'a> {
             _ => {}
         }
 
-        map(Relationship::parse, Entity::Relationship)(input)
+        if let Ok((input, segment)) = PathSegment::parse(input) {
+            return Ok((input, Entity::Segment(segment)));
+        }
+
+        let (input, relationship) = Relationship::parse(input)?;
+        match Quantifier::parse(input) {
+            Ok((input, quantifier)) => Ok((
+                input,
+                Entity::QuantifiedRelationship(relationship, quantifier),
+            )),
+            Err(_) => Ok((input, Entity::Relationship(relationship))),
+        }
     }
 }
 
@@ -40,7 +63,7 @@ mod tests {
             " data",
             Entity::Node(Node {
                 variable: None,
-                labels: vec![],
+                labels: None,
                 properties: None,
             }),
         ));
@@ -54,8 +77,10 @@ mod tests {
             " data",
             Entity::Relationship(Relationship {
                 variable: None,
-                labels: vec![],
+                labels: None,
+                length: None,
                 properties: None,
+                bracketed: true,
                 right_line: Line("-"),
                 left_line: Line("-"),
             }),
@@ -2,11 +2,14 @@ use nom::{
     character::complete::{space0, space1},
     combinator::map,
     multi::many0,
-    sequence::tuple,
+    sequence::{terminated, tuple},
     IResult,
 };
 
-use crate::{atom::Atom, label::Label, property_lookup::PropertyLookup};
+use crate::{
+    atom::Atom, format_options::FormatOptions, label::LabelExpression,
+    property_lookup::PropertyLookup, shared::optional,
+};
 
 use super::bool_keyword::BoolKeyword;
 
@@ -15,22 +18,40 @@ pub struct BoolExpression<'a> {
     pub keyword: BoolKeyword,
     pub atom: Atom<'a>,
     pub property_lookups: Vec<PropertyLookup<'a>>,
-    pub labels: Vec<Label<'a>>,
+    pub labels: Option<LabelExpression<'a>>,
 }
 
 impl<'a> BoolExpression<'a> {
+    pub fn format(&self) -> String {
+        self.format_at(&FormatOptions::default(), 0)
+    }
+
+    pub fn format_at(&self, options: &FormatOptions, column: usize) -> String {
+        let keyword = self.keyword.format();
+        format!(
+            "{} {}{}{}",
+            keyword,
+            self.atom.format_at(options, column + keyword.len() + 1),
+            self.property_lookups
+                .iter()
+                .map(|p| p.format())
+                .collect::<String>(),
+            self.labels
+                .as_ref()
+                .map(|l| format!(":{}", l.format_with(options)))
+                .unwrap_or_default()
+        )
+    }
+
     pub fn parse(input: &'a str) -> IResult<&str, Self> {
         let (input, keyword) =
             map(tuple((BoolKeyword::parse, space1)), |(result, _)| result)(input)?;
 
-        let (input, atom) = map(tuple((Atom::parse, space1)), |(result, _)| result)(input)?;
+        let (input, atom) = map(tuple((Atom::parse, space0)), |(result, _)| result)(input)?;
 
         let (input, property_lookups) = many0(PropertyLookup::parse)(input)?;
 
-        let (input, labels) = many0(map(
-            tuple((space0, Label::parse, space0)),
-            |(_, result, _)| result,
-        ))(input)?;
+        let (input, labels) = optional(terminated(LabelExpression::parse, space0))(input)?;
 
         Ok((
             input,
@@ -58,7 +79,7 @@ mod tests {
                 keyword: BoolKeyword::StartsWith,
                 atom: Atom::Literal(Literal::String(string::String("'Pet'"))),
                 property_lookups: vec![],
-                labels: vec![],
+                labels: None,
             },
         ));
         let actual = BoolExpression::parse("STARTS WITH 'Pet' data");
@@ -73,7 +94,7 @@ mod tests {
                 keyword: BoolKeyword::EndsWith,
                 atom: Atom::Literal(Literal::String(string::String("'Pet'"))),
                 property_lookups: vec![],
-                labels: vec![],
+                labels: None,
             },
         ));
         let actual = BoolExpression::parse("Ends With 'Pet' data");
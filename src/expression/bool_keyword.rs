@@ -12,6 +12,16 @@ pub enum BoolKeyword {
 }
 
 impl BoolKeyword {
+    pub fn format(&self) -> String {
+        match self {
+            Self::StartsWith => "STARTS WITH",
+            Self::EndsWith => "ENDS WITH",
+            Self::Contains => "CONTAINS",
+            Self::In => "IN",
+        }
+        .to_string()
+    }
+
     fn parse_starts_with(input: &str) -> IResult<&str, Self> {
         map(
             tuple((tag_no_case("STARTS"), space1, tag_no_case("WITH"))),
@@ -1,5 +1,7 @@
 use nom::{branch::alt, combinator::map, IResult};
 
+use crate::format_options::FormatOptions;
+
 use super::{
     bool_expression::BoolExpression, list_expression::ListExpression,
     null_expression::NullExpression,
@@ -13,6 +15,18 @@ pub enum BoolOrListExpression<'a> {
 }
 
 impl<'a> BoolOrListExpression<'a> {
+    pub fn format(&self) -> String {
+        self.format_at(&FormatOptions::default(), 0)
+    }
+
+    pub fn format_at(&self, options: &FormatOptions, column: usize) -> String {
+        match self {
+            BoolOrListExpression::Null(n) => format!(" {}", n.format()),
+            BoolOrListExpression::Bool(b) => format!(" {}", b.format_at(options, column + 1)),
+            BoolOrListExpression::List(l) => l.format_at(options, column),
+        }
+    }
+
     pub fn parse(input: &'a str) -> IResult<&str, Self> {
         alt((
             map(NullExpression::parse, BoolOrListExpression::Null),
@@ -4,7 +4,12 @@ use nom::{
 };
 
 use crate::{
-    atom::Atom, label::Label, math_op::MathOp, property_lookup::PropertyLookup, shared::optional,
+    atom::Atom,
+    format_options::FormatOptions,
+    label::LabelExpression,
+    math_op::MathOp,
+    property_lookup::PropertyLookup,
+    shared::{column_after, join_at, optional},
 };
 
 use super::bool_or_list_expression::BoolOrListExpression;
@@ -16,6 +21,14 @@ pub enum AddOrSub {
 }
 
 impl AddOrSub {
+    pub fn format(&self) -> String {
+        match self {
+            AddOrSub::Add => "+",
+            AddOrSub::Sub => "-",
+        }
+        .to_string()
+    }
+
     pub fn parse(input: &str) -> IResult<&str, Self> {
         alt((
             map(tag("+"), |_| AddOrSub::Add),
@@ -29,14 +42,59 @@ pub struct CalculableExpression<'a> {
     pub add_or_subs: Vec<AddOrSub>,
     pub atom: Atom<'a>,
     pub property_lookups: Vec<PropertyLookup<'a>>,
-    pub labels: Vec<Label<'a>>,
+    pub labels: Option<LabelExpression<'a>>,
     pub bool_or_list_expressions: Vec<BoolOrListExpression<'a>>,
     pub math_op: Option<MathOp>,
 }
 
 impl<'a> CalculableExpression<'a> {
+    fn add_or_subs_str(&self) -> String {
+        self.add_or_subs.iter().map(|a| a.format()).collect()
+    }
+
+    fn property_lookups_str(&self) -> String {
+        self.property_lookups.iter().map(|p| p.format()).collect()
+    }
+
+    fn labels_str(&self, options: &FormatOptions) -> String {
+        self.labels
+            .as_ref()
+            .map(|l| format!(":{}", l.format_with(options)))
+            .unwrap_or_default()
+    }
+
+    fn math_op_str(&self) -> String {
+        self.math_op
+            .as_ref()
+            .map(|m| format!(" {}", m.format()))
+            .unwrap_or_default()
+    }
+
     pub fn format(&self) -> String {
-        format!("{}", self.atom.format())
+        self.format_at(&FormatOptions::default(), 0)
+    }
+
+    pub fn format_at(&self, options: &FormatOptions, column: usize) -> String {
+        let add_or_subs = self.add_or_subs_str();
+        let operand = format!(
+            "{}{}{}{}",
+            add_or_subs,
+            self.atom.format_at(options, column + add_or_subs.len()),
+            self.property_lookups_str(),
+            self.labels_str(options)
+        );
+        let bool_or_list_expressions = join_at(
+            &self.bool_or_list_expressions,
+            "",
+            column_after(column, &operand),
+            |b, column| b.format_at(options, column),
+        );
+        format!(
+            "{}{}{}",
+            operand,
+            bool_or_list_expressions,
+            self.math_op_str()
+        )
     }
 
     pub fn parse(input: &'a str) -> IResult<&str, Self> {
@@ -46,12 +104,14 @@ impl<'a> CalculableExpression<'a> {
         let (input, atom) = Atom::parse(input)?;
         let (input, _) = space0(input)?;
         let (input, property_lookups) = many0(PropertyLookup::parse)(input)?;
-        let (input, labels) = many0(Label::parse)(input)?;
+        let (input, labels) = optional(LabelExpression::parse)(input)?;
+        let (input, _) = space0(input)?;
         let (input, bool_or_list_expressions) = many0(map(
             tuple((BoolOrListExpression::parse, space0)),
             |(result, _)| result,
         ))(input)?;
         let (input, math_op) = optional(MathOp::parse)(input)?;
+        let (input, _) = space0(input)?;
 
         Ok((
             input,
@@ -72,7 +132,7 @@ impl<'a> Default for CalculableExpression<'a> {
         CalculableExpression {
             add_or_subs: vec![],
             atom: Atom::Variable(""),
-            labels: vec![],
+            labels: None,
             property_lookups: vec![],
             math_op: None,
             bool_or_list_expressions: vec![],
@@ -2,12 +2,16 @@ use nom::{
     bytes::complete::tag_no_case,
     character::complete::{space0, space1},
     combinator::map,
-    multi::{many0, many1},
-    sequence::tuple,
+    multi::many0,
+    sequence::{terminated, tuple},
     IResult,
 };
 
-use crate::{combinator::Combinator, shared::optional};
+use crate::{
+    combinator::Combinator,
+    format_options::FormatOptions,
+    shared::{join_at, optional},
+};
 
 use super::CalculableExpression;
 
@@ -43,33 +47,41 @@ impl<'a> CombinableExpression<'a> {
     }
 
     fn parse_calcualables(input: &'a str) -> IResult<&str, Vec<CalculableExpression<'a>>> {
-        let (input, calculable) = Self::parse_calculable(input)?;
-        if calculable.math_op.is_none() {
-            return Ok((input, vec![calculable]));
-        }
-
-        let (input, mut calculables_rest) = many1(Self::parse_calculable)(input)?;
+        let (mut input, calculable) = Self::parse_calculable(input)?;
         let mut calculables = vec![calculable];
-        calculables.append(&mut calculables_rest);
+
+        while calculables.last().is_some_and(|c| c.math_op.is_some()) {
+            let (rest, calculable) = Self::parse_calculable(input)?;
+            input = rest;
+            calculables.push(calculable);
+        }
 
         Ok((input, calculables))
     }
 
+    fn combinator_str(&self) -> String {
+        self.combinator
+            .as_ref()
+            .map(|c| format!(" {}", c.format()))
+            .unwrap_or_default()
+    }
+
     pub fn format(&self) -> String {
-        format!(
-            "{}",
-            self.calculables
-                .iter()
-                .map(|c| c.format())
-                .collect::<Vec<_>>()
-                .join(" ")
-        )
+        self.format_at(&FormatOptions::default(), 0)
+    }
+
+    pub fn format_at(&self, options: &FormatOptions, column: usize) -> String {
+        let nots = "NOT ".repeat(self.not_count);
+        let calculables = join_at(&self.calculables, " ", column + nots.len(), |c, column| {
+            c.format_at(options, column)
+        });
+        format!("{}{}{}", nots, calculables, self.combinator_str())
     }
 
     pub fn parse(input: &'a str) -> IResult<&str, Self> {
         let (input, not_count) = Self::parse_nots(input)?;
         let (input, calculables) = Self::parse_calcualables(input)?;
-        let (input, combinator) = optional(Combinator::parse)(input)?;
+        let (input, combinator) = optional(terminated(Combinator::parse, space0))(input)?;
 
         Ok((
             input,
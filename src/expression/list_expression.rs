@@ -7,7 +7,10 @@ use nom::{
     IResult,
 };
 
-use crate::shared::optional;
+use crate::{
+    format_options::FormatOptions,
+    shared::{column_after, optional},
+};
 
 use super::Expression;
 
@@ -18,6 +21,29 @@ pub enum ListExpression<'a> {
 }
 
 impl<'a> ListExpression<'a> {
+    pub fn format(&self) -> String {
+        self.format_at(&FormatOptions::default(), 0)
+    }
+
+    pub fn format_at(&self, options: &FormatOptions, column: usize) -> String {
+        match self {
+            ListExpression::Single(expression) => {
+                format!("[{}]", expression.format_at(options, column + 1))
+            }
+            ListExpression::Dotted((first, second)) => {
+                let first = first
+                    .as_ref()
+                    .map(|e| e.format_at(options, column + 1))
+                    .unwrap_or_default();
+                let second = second
+                    .as_ref()
+                    .map(|e| e.format_at(options, column_after(column + 1, &first) + 2))
+                    .unwrap_or_default();
+                format!("[{}..{}]", first, second)
+            }
+        }
+    }
+
     fn parse_single(input: &'a str) -> IResult<&str, Self> {
         map(
             tuple((space0, delimited(tag("["), Expression::parse, tag("]")))),
@@ -6,18 +6,22 @@ pub mod combinable_expression;
 pub mod list_expression;
 pub mod null_expression;
 
-use nom::{combinator::map, multi::many1, IResult};
+use nom::IResult;
 
 use crate::{
     atom::Atom,
     expression::calculable_expression::CalculableExpression,
+    format_options::FormatOptions,
     literal::{
         bool::Bool,
         integer::{Decimal, Integer},
         list::List,
+        map::Map,
         number::Number,
         string, Literal,
     },
+    parameter::Parameter,
+    shared::join_at,
 };
 
 use self::{
@@ -35,27 +39,31 @@ pub struct Expression<'a> {
 
 impl<'a> Expression<'a> {
     pub fn format(&self) -> String {
-        self.expressions
-            .iter()
-            .map(|e| e.format())
-            .collect::<Vec<_>>()
-            .join(" ")
+        self.format_with(&FormatOptions::default())
+    }
+
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        self.format_at(options, 0)
+    }
+
+    // `column` is where the expression starts on its line, so nested
+    // subqueries can tell whether they still fit inline.
+    pub fn format_at(&self, options: &FormatOptions, column: usize) -> String {
+        join_at(&self.expressions, " ", column, |e, column| {
+            e.format_at(options, column)
+        })
     }
 
     pub fn parse(input: &'a str) -> IResult<&str, Self> {
-        let (input, expression) = CombinableExpression::parse(input)?;
-        if expression.combinator.is_none() {
-            return Ok((
-                input,
-                Expression {
-                    expressions: vec![expression],
-                },
-            ));
+        let (mut input, expression) = CombinableExpression::parse(input)?;
+        let mut expressions = vec![expression];
+
+        while expressions.last().is_some_and(|e| e.combinator.is_some()) {
+            let (rest, expression) = CombinableExpression::parse(input)?;
+            input = rest;
+            expressions.push(expression);
         }
 
-        let mut expressions = vec![expression];
-        let (input, mut expressions_rest) = many1(CombinableExpression::parse)(input)?;
-        expressions.append(&mut expressions_rest);
         Ok((input, Expression { expressions }))
     }
 }
@@ -99,6 +107,18 @@ impl<'a> Expression<'a> {
         }
     }
 
+    pub fn parameter(p: &'a str) -> Self {
+        Expression {
+            expressions: vec![CombinableExpression {
+                calculables: vec![CalculableExpression {
+                    atom: Atom::Parameter(Parameter(p)),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+        }
+    }
+
     pub fn bool(b: bool) -> Self {
         Expression {
             expressions: vec![CombinableExpression {
@@ -111,6 +131,18 @@ impl<'a> Expression<'a> {
         }
     }
 
+    pub fn map(map: Map<'a>) -> Self {
+        Expression {
+            expressions: vec![CombinableExpression {
+                calculables: vec![CalculableExpression {
+                    atom: Atom::Literal(Literal::Map(map)),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+        }
+    }
+
     pub fn list_of_decimal_ints(ints: &[&'a str]) -> Self {
         let ints = ints.iter().map(|s| Self::decimal_int(s)).collect();
         Expression {
@@ -10,6 +10,14 @@ pub enum NullExpression {
 }
 
 impl NullExpression {
+    pub fn format(&self) -> String {
+        match self {
+            Self::IsNull => "IS NULL",
+            Self::IsNotNull => "IS NOT NULL",
+        }
+        .to_string()
+    }
+
     fn parse_is_null(input: &str) -> IResult<&str, Self> {
         map(
             tuple((tag_no_case("IS"), space1, tag_no_case("NULL"))),
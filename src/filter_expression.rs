@@ -15,15 +15,22 @@ pub struct FilterExpression<'a> {
     pub where_expression: Option<Expression<'a>>,
 }
 
-impl<'a> FilterExpression<'a> {
-    pub fn parse(input: &'a str) -> IResult<&str, Self> {
-        let (input, (variable, _, _, _, expression)) = tuple((
+pub fn parse_id_in_coll<'a>(input: &'a str) -> IResult<&str, (&str, Expression<'a>)> {
+    map(
+        tuple((
             symbolic_name::parse,
             space1,
             tag_no_case("IN"),
             space1,
             Expression::parse,
-        ))(input)?;
+        )),
+        |(variable, _, _, _, expression)| (variable, expression),
+    )(input)
+}
+
+impl<'a> FilterExpression<'a> {
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        let (input, (variable, expression)) = parse_id_in_coll(input)?;
 
         let (input, _) = space0(input)?;
 
@@ -0,0 +1,20 @@
+#[derive(Debug, Default)]
+pub struct FormatOptions {
+    /// Print `ASCENDING`/`DESCENDING` sort orders as `ASC`/`DESC`.
+    pub normalize_order: bool,
+    pub relationship_brackets: RelationshipBrackets,
+    /// Print the legacy `:A:B` label conjunction as `:A&B`.
+    pub normalize_label_conjunction: bool,
+}
+
+/// How to print relationships without a variable, type, length or properties.
+#[derive(Debug, Default, PartialEq)]
+pub enum RelationshipBrackets {
+    /// Print them the way they were written.
+    #[default]
+    Keep,
+    /// Always print empty brackets, `-[]->`.
+    Expand,
+    /// Never print empty brackets, `-->`.
+    Collapse,
+}
@@ -2,7 +2,7 @@ use nom::{
     bytes::complete::tag, character::complete::space0, combinator::map, sequence::tuple, IResult,
 };
 
-use crate::{expression::Expression, token};
+use crate::{expression::Expression, format_options::FormatOptions, token};
 
 #[derive(Debug, PartialEq)]
 pub struct KeyValue<'a> {
@@ -12,12 +12,21 @@ pub struct KeyValue<'a> {
 
 impl<'a> KeyValue<'a> {
     pub fn format(&self) -> String {
-        format!("{}: {}", self.key, self.value.format())
+        self.format_at(&FormatOptions::default(), 0)
     }
+
+    pub fn format_at(&self, options: &FormatOptions, column: usize) -> String {
+        format!(
+            "{}: {}",
+            self.key,
+            self.value.format_at(options, column + self.key.len() + 2)
+        )
+    }
+
     pub fn parse(input: &'a str) -> IResult<&str, Self> {
         map(
             tuple((
-                token::parse,
+                token::parse_escaped,
                 tuple((space0, tag(":"), space0)),
                 Expression::parse,
             )),
@@ -56,4 +65,18 @@ mod tests {
         let actual = KeyValue::parse("a:[1,2,3]").unwrap().1.format();
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn parse_key_value_escaped_key() {
+        let expected = Ok((
+            "",
+            KeyValue {
+                key: "`vector.dimensions`",
+                value: Expression::decimal_int("1536"),
+            },
+        ));
+
+        let actual = KeyValue::parse("`vector.dimensions`: 1536");
+        assert_eq!(expected, actual);
+    }
 }
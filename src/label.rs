@@ -1,13 +1,14 @@
 use nom::{
+    branch::alt,
     bytes::complete::tag,
     character::complete::space0,
-    combinator::map,
-    multi::many1,
-    sequence::{preceded, tuple},
+    combinator::{map, verify},
+    multi::{many0, many1},
+    sequence::{delimited, preceded, tuple},
     IResult,
 };
 
-use crate::token;
+use crate::{format_options::FormatOptions, shared::optional, token};
 
 #[derive(Debug, PartialEq)]
 pub struct Label<'a>(pub &'a str);
@@ -28,6 +29,126 @@ impl<'a> Label<'a> {
     }
 }
 
+// Precedence from loosest to tightest is `|`, then `&` (or the legacy `:`),
+// then `!`. Parentheses are kept so the expression prints as written.
+#[derive(Debug, PartialEq)]
+pub enum LabelExpression<'a> {
+    Label(Label<'a>),
+    Wildcard,
+    Not(Box<LabelExpression<'a>>),
+    Group(Box<LabelExpression<'a>>),
+    And(Vec<LabelExpression<'a>>),
+    Or(Vec<LabelExpression<'a>>),
+    Colon(Vec<LabelExpression<'a>>),
+}
+
+impl<'a> LabelExpression<'a> {
+    pub fn format(&self) -> String {
+        self.format_with(&FormatOptions::default())
+    }
+
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        let join = |expressions: &Vec<LabelExpression>, separator| {
+            expressions
+                .iter()
+                .map(|e| e.format_with(options))
+                .collect::<Vec<_>>()
+                .join(separator)
+        };
+        match self {
+            LabelExpression::Label(l) => l.format(),
+            LabelExpression::Wildcard => "%".to_string(),
+            LabelExpression::Not(e) => format!("!{}", e.format_with(options)),
+            LabelExpression::Group(e) => format!("({})", e.format_with(options)),
+            LabelExpression::And(expressions) => join(expressions, "&"),
+            LabelExpression::Or(expressions) => join(expressions, "|"),
+            LabelExpression::Colon(expressions) if options.normalize_label_conjunction => {
+                join(expressions, "&")
+            }
+            LabelExpression::Colon(expressions) => join(expressions, ":"),
+        }
+    }
+
+    pub fn has_colon_conjunction(&self) -> bool {
+        match self {
+            LabelExpression::Label(_) | LabelExpression::Wildcard => false,
+            LabelExpression::Not(e) | LabelExpression::Group(e) => e.has_colon_conjunction(),
+            LabelExpression::And(expressions) | LabelExpression::Or(expressions) => {
+                expressions.iter().any(|e| e.has_colon_conjunction())
+            }
+            LabelExpression::Colon(_) => true,
+        }
+    }
+
+    fn parse_primary(input: &'a str) -> IResult<&str, Self> {
+        alt((
+            map(
+                delimited(
+                    tuple((tag("("), space0)),
+                    Self::parse_or,
+                    tuple((space0, tag(")"))),
+                ),
+                |e| LabelExpression::Group(Box::new(e)),
+            ),
+            map(tag("%"), |_| LabelExpression::Wildcard),
+            map(token::parse, |l| LabelExpression::Label(Label(l))),
+        ))(input)
+    }
+
+    fn parse_not(input: &'a str) -> IResult<&str, Self> {
+        alt((
+            map(preceded(tuple((tag("!"), space0)), Self::parse_not), |e| {
+                LabelExpression::Not(Box::new(e))
+            }),
+            Self::parse_primary,
+        ))(input)
+    }
+
+    fn parse_and(input: &'a str) -> IResult<&str, Self> {
+        let (input, first) = Self::parse_not(input)?;
+        let (input, rest) = verify(
+            many0(tuple((
+                delimited(space0, alt((tag("&"), tag(":"))), space0),
+                Self::parse_not,
+            ))),
+            |rest: &[(&str, LabelExpression)]| rest.windows(2).all(|w| w[0].0 == w[1].0),
+        )(input)?;
+        if rest.is_empty() {
+            return Ok((input, first));
+        }
+
+        let legacy = rest[0].0 == ":";
+        let expressions = std::iter::once(first)
+            .chain(rest.into_iter().map(|(_, e)| e))
+            .collect();
+        if legacy {
+            return Ok((input, LabelExpression::Colon(expressions)));
+        }
+        Ok((input, LabelExpression::And(expressions)))
+    }
+
+    // The legacy `|:` separator of relationship types is read as a plain `|`.
+    fn parse_or(input: &'a str) -> IResult<&str, Self> {
+        let (input, first) = Self::parse_and(input)?;
+        let (input, rest) = many0(preceded(
+            tuple((space0, tag("|"), space0, optional(tag(":")), space0)),
+            Self::parse_and,
+        ))(input)?;
+        if rest.is_empty() {
+            return Ok((input, first));
+        }
+
+        Ok((
+            input,
+            LabelExpression::Or(std::iter::once(first).chain(rest).collect()),
+        ))
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        preceded(tuple((space0, tag(":"), space0)), Self::parse_or)(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -45,4 +166,46 @@ mod tests {
         let actual = Label::parse_many1(":ALabel:BLabel data");
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn parse_label_expression() {
+        let expected = Ok((
+            " data",
+            LabelExpression::Or(vec![
+                LabelExpression::And(vec![
+                    LabelExpression::Label(Label("A")),
+                    LabelExpression::Not(Box::new(LabelExpression::Label(Label("B")))),
+                ]),
+                LabelExpression::Wildcard,
+            ]),
+        ));
+        let actual = LabelExpression::parse(":A & !B | % data");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_label_expression() {
+        let format = |input| LabelExpression::parse(input).unwrap().1.format();
+        assert_eq!("(A|B)&!(C|%)", format(":( A|B )&!(C | %)"));
+        assert_eq!("A:B", format(":A:B"));
+        assert_eq!("A|B", format(":A|:B"));
+    }
+
+    #[test]
+    fn parse_label_expression_mixed_conjunction() {
+        assert!(LabelExpression::parse(":A&B:C").is_err());
+    }
+
+    #[test]
+    fn format_label_expression_normalized_conjunction() {
+        let options = FormatOptions {
+            normalize_label_conjunction: true,
+            ..Default::default()
+        };
+        let actual = LabelExpression::parse(":A:B|C")
+            .unwrap()
+            .1
+            .format_with(&options);
+        assert_eq!("A&B|C", actual);
+    }
 }
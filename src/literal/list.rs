@@ -7,20 +7,21 @@ use nom::{
     IResult,
 };
 
-use crate::expression::Expression;
+use crate::{expression::Expression, format_options::FormatOptions, shared::join_at};
 
 #[derive(Debug, PartialEq)]
 pub struct List<'a>(pub Vec<Expression<'a>>);
 
 impl<'a> List<'a> {
     pub fn format(&self) -> String {
+        self.format_at(&FormatOptions::default(), 0)
+    }
+
+    pub fn format_at(&self, options: &FormatOptions, column: usize) -> String {
         format!(
             "[{}]",
-            self.0
-                .iter()
-                .map(|e| e.format())
-                .collect::<Vec<_>>()
-                .join(", ")
+            join_at(&self.0, ", ", column + 1, |e, column| e
+                .format_at(options, column))
         )
     }
 
@@ -7,24 +7,21 @@ use nom::{
     IResult,
 };
 
-use crate::key_value::KeyValue;
+use crate::{format_options::FormatOptions, key_value::KeyValue, shared::join_at};
 
 #[derive(Debug, PartialEq)]
 pub struct Map<'a>(pub Vec<KeyValue<'a>>);
 
 impl<'a> Map<'a> {
     pub fn format(&self) -> String {
-        if self.0.len() == 0 {
-            return "".to_string();
-        }
+        self.format_at(&FormatOptions::default(), 0)
+    }
 
+    pub fn format_at(&self, options: &FormatOptions, column: usize) -> String {
         format!(
             "{{{}}}",
-            self.0
-                .iter()
-                .map(|kv| kv.format())
-                .collect::<Vec<_>>()
-                .join(", ")
+            join_at(&self.0, ", ", column + 1, |kv, column| kv
+                .format_at(options, column))
         )
     }
 
@@ -60,6 +57,11 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn format_empty_map() {
+        assert_eq!("{}", Map::parse("{ }").unwrap().1.format());
+    }
+
     #[test]
     fn parse_map_single() {
         let expected = Ok((
@@ -1,5 +1,7 @@
 use nom::{combinator::map, IResult};
 
+use crate::format_options::FormatOptions;
+
 use self::{bool::Bool, list::List, map::Map, null::Null, number::Number, string::String};
 
 pub mod bool;
@@ -23,13 +25,17 @@ pub enum Literal<'a> {
 
 impl<'a> Literal<'a> {
     pub fn format(&self) -> std::string::String {
+        self.format_at(&FormatOptions::default(), 0)
+    }
+
+    pub fn format_at(&self, options: &FormatOptions, column: usize) -> std::string::String {
         match self {
             Literal::Null(n) => n.format(),
             Literal::Bool(b) => b.format(),
             Literal::Number(n) => n.format(),
             Literal::String(s) => s.format(),
-            Literal::List(l) => l.format(),
-            Literal::Map(p) => p.format(),
+            Literal::List(l) => l.format_at(options, column),
+            Literal::Map(p) => p.format_at(options, column),
         }
     }
 
@@ -3,8 +3,10 @@ use node::Node;
 
 use crate::cypher::Cypher;
 
+mod administration_command;
 mod atom;
 mod filter_expression;
+mod format_options;
 mod case;
 mod clause;
 mod count;
@@ -20,11 +22,16 @@ mod math_op;
 mod node;
 mod parameter;
 mod pattern;
+mod procedure_invocation;
 mod projection_body;
 mod property_lookup;
+mod query_prefix;
+mod range_literal;
 mod relationship;
 mod r#return;
+mod schema_command;
 mod shared;
+mod shortest_path;
 mod single_part_query;
 mod subclause;
 mod symbolic_name;
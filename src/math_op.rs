@@ -17,6 +17,25 @@ pub enum MathOp {
 }
 
 impl MathOp {
+    pub fn format(&self) -> String {
+        use MathOp::*;
+        match self {
+            Add => "+",
+            Sub => "-",
+            Div => "/",
+            Mult => "*",
+            Mod => "%",
+            Exp => "^",
+            Equal => "=",
+            NotEqual => "<>",
+            LT => "<",
+            GT => ">",
+            LTE => "<=",
+            GTE => ">=",
+        }
+        .to_string()
+    }
+
     pub fn parse(input: &str) -> IResult<&str, Self> {
         use MathOp::*;
         alt((
@@ -24,14 +43,33 @@ impl MathOp {
             map(tag("-"), |_| Sub),
             map(tag("/"), |_| Div),
             map(tag("*"), |_| Mult),
-            map(tag("^"), |_| Mult),
-            map(tag("%"), |_| Mult),
+            map(tag("^"), |_| Exp),
+            map(tag("%"), |_| Mod),
             map(tag("="), |_| Equal),
             map(tag("<>"), |_| NotEqual),
-            map(tag("<"), |_| LT),
-            map(tag(">"), |_| GT),
             map(tag("<="), |_| LTE),
             map(tag(">="), |_| GTE),
+            map(tag("<"), |_| LT),
+            map(tag(">"), |_| GT),
         ))(input)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_math_op_lte() {
+        let expected = Ok((" data", MathOp::LTE));
+        let actual = MathOp::parse("<= data");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_math_op_mod() {
+        let expected = "%";
+        let actual = MathOp::parse("%").unwrap().1.format();
+        assert_eq!(expected, actual);
+    }
+}
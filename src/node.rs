@@ -6,12 +6,12 @@ use nom::{
     IResult,
 };
 
-use crate::{label::Label, literal::map::Map, token};
+use crate::{format_options::FormatOptions, label::LabelExpression, literal::map::Map, token};
 
 #[derive(Debug, PartialEq)]
 pub struct Node<'a> {
     pub variable: Option<&'a str>,
-    pub labels: Vec<Label<'a>>,
+    pub labels: Option<LabelExpression<'a>>,
     pub properties: Option<Map<'a>>,
 }
 
@@ -23,7 +23,10 @@ fn parse_token<'a>(input: &'a str) -> IResult<&str, Option<&str>> {
 }
 
 fn parse_properties<'a>(input: &'a str) -> IResult<&str, Option<Map<'a>>> {
-    map(tuple((opt(Map::parse), space0)), |(result, _)| result)(input)
+    map(
+        tuple((space0, opt(Map::parse), space0)),
+        |(_, result, _)| result,
+    )(input)
 }
 
 impl<'a> Node<'a> {
@@ -33,19 +36,11 @@ impl<'a> Node<'a> {
             .unwrap_or("".to_string())
     }
 
-    fn labels_str(&self) -> String {
-        if self.labels.len() == 0 {
-            return "".to_string();
-        }
-
-        format!(
-            ":{}",
-            self.labels
-                .iter()
-                .map(|l| l.format())
-                .collect::<Vec<_>>()
-                .join(":")
-        )
+    fn labels_str(&self, options: &FormatOptions) -> String {
+        self.labels
+            .as_ref()
+            .map(|l| format!(":{}", l.format_with(options)))
+            .unwrap_or_default()
     }
 
     fn properties_str(&self) -> String {
@@ -56,10 +51,14 @@ impl<'a> Node<'a> {
     }
 
     pub fn format(&self) -> String {
+        self.format_with(&FormatOptions::default())
+    }
+
+    pub fn format_with(&self, options: &FormatOptions) -> String {
         format!(
             "({}{}{})",
             self.variable_str(),
-            self.labels_str(),
+            self.labels_str(options),
             self.properties_str()
         )
     }
@@ -68,12 +67,12 @@ impl<'a> Node<'a> {
         map(
             delimited(
                 tag("("),
-                tuple((parse_token, opt(Label::parse_many1), parse_properties)),
+                tuple((parse_token, opt(LabelExpression::parse), parse_properties)),
                 tag(")"),
             ),
             |(variable, labels, properties)| Node {
                 variable,
-                labels: labels.unwrap_or(vec![]),
+                labels,
                 properties,
             },
         )(input)
@@ -82,7 +81,7 @@ impl<'a> Node<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{expression::Expression, key_value::KeyValue};
+    use crate::{expression::Expression, key_value::KeyValue, label::Label};
 
     use super::*;
 
@@ -92,7 +91,7 @@ mod tests {
             " data",
             Node {
                 variable: None,
-                labels: vec![],
+                labels: None,
                 properties: None,
             },
         ));
@@ -107,7 +106,7 @@ mod tests {
             " data",
             Node {
                 variable: None,
-                labels: vec![Label("ALabel")],
+                labels: Some(LabelExpression::Label(Label("ALabel"))),
                 properties: None,
             },
         ));
@@ -122,7 +121,10 @@ mod tests {
             " data",
             Node {
                 variable: None,
-                labels: vec![Label("ALabel"), Label("BLabel")],
+                labels: Some(LabelExpression::Colon(vec![
+                    LabelExpression::Label(Label("ALabel")),
+                    LabelExpression::Label(Label("BLabel")),
+                ])),
                 properties: None,
             },
         ));
@@ -137,7 +139,7 @@ mod tests {
             " data",
             Node {
                 variable: None,
-                labels: vec![],
+                labels: None,
                 properties: Some(Map(vec![KeyValue {
                     key: "some_key",
                     value: Expression::decimal_int("10"),
@@ -149,13 +151,31 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn parse_node_label_space_properties() {
+        let expected = Ok((
+            "",
+            Node {
+                variable: Some("n"),
+                labels: Some(LabelExpression::Label(Label("ALabel"))),
+                properties: Some(Map(vec![KeyValue {
+                    key: "some_key",
+                    value: Expression::decimal_int("10"),
+                }])),
+            },
+        ));
+
+        let actual = Node::parse("(n:ALabel {some_key: 10})");
+        assert_eq!(expected, actual);
+    }
+
     #[test]
     fn parse_node_variable() {
         let expected = Ok((
             " data",
             Node {
                 variable: Some("myVar"),
-                labels: vec![],
+                labels: None,
                 properties: None,
             },
         ));
@@ -170,7 +190,7 @@ mod tests {
             " data",
             Node {
                 variable: Some("myVar"),
-                labels: vec![Label("ALabel")],
+                labels: Some(LabelExpression::Label(Label("ALabel"))),
                 properties: None,
             },
         ));
@@ -185,7 +205,7 @@ mod tests {
             " data",
             Node {
                 variable: Some("myVar"),
-                labels: vec![Label("ALabel")],
+                labels: Some(LabelExpression::Label(Label("ALabel"))),
                 properties: Some(Map(vec![KeyValue {
                     key: "some_key",
                     value: Expression::decimal_int("10"),
@@ -1,32 +1,357 @@
-use nom::{combinator::map, multi::many1, IResult};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{digit1, multispace0, multispace1, space0},
+    combinator::map,
+    multi::{many0, separated_list1},
+    sequence::{delimited, preceded, terminated, tuple},
+    IResult,
+};
 
-use crate::entity::Entity;
+use crate::{
+    entity::Entity,
+    format_options::FormatOptions,
+    shared::{keyword, optional, parse_keyword_of, MAX_LINE_WIDTH},
+    shortest_path::ShortestPath,
+    subclause::r#where::Where,
+    token,
+};
+
+#[derive(Debug, PartialEq)]
+pub enum Quantifier<'a> {
+    Plus,
+    Star,
+    Exact(&'a str),
+    Range(Option<&'a str>, Option<&'a str>),
+}
+
+impl<'a> Quantifier<'a> {
+    pub fn format(&self) -> String {
+        match self {
+            Quantifier::Plus => "+".to_string(),
+            Quantifier::Star => "*".to_string(),
+            Quantifier::Exact(n) => format!("{{{}}}", n),
+            Quantifier::Range(min, max) => {
+                format!("{{{},{}}}", min.unwrap_or(""), max.unwrap_or(""))
+            }
+        }
+    }
+
+    fn parse_braces(input: &'a str) -> IResult<&str, Self> {
+        map(
+            delimited(
+                tuple((tag("{"), space0)),
+                tuple((
+                    optional(digit1),
+                    optional(preceded(
+                        tuple((space0, tag(","), space0)),
+                        optional(digit1),
+                    )),
+                )),
+                tuple((space0, tag("}"))),
+            ),
+            |quantifier| match quantifier {
+                (Some(n), None) => Quantifier::Exact(n),
+                (min, Some(max)) => Quantifier::Range(min, max),
+                (None, None) => Quantifier::Range(None, None),
+            },
+        )(input)
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        alt((
+            map(tag("+"), |_| Quantifier::Plus),
+            map(tag("*"), |_| Quantifier::Star),
+            Self::parse_braces,
+        ))(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PathSegment<'a> {
+    pub pattern: Pattern<'a>,
+    pub r#where: Option<Where<'a>>,
+    pub quantifier: Option<Quantifier<'a>>,
+}
+
+impl<'a> PathSegment<'a> {
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        format!(
+            "({}{}){}",
+            self.pattern.format_with(options),
+            self.r#where
+                .as_ref()
+                .map(|w| format!(" {}", w.format_with(options)))
+                .unwrap_or_default(),
+            self.quantifier
+                .as_ref()
+                .map(|q| q.format())
+                .unwrap_or_default()
+        )
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                tag("("),
+                multispace0,
+                Pattern::parse,
+                optional(preceded(multispace0, Where::parse)),
+                multispace0,
+                tag(")"),
+                optional(preceded(space0, Quantifier::parse)),
+            )),
+            |(_, _, pattern, r#where, _, _, quantifier)| PathSegment {
+                pattern,
+                r#where,
+                quantifier,
+            },
+        )(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PathSelectorKind<'a> {
+    All,
+    Any(Option<&'a str>),
+    AllShortest,
+    AnyShortest,
+    Shortest(Option<&'a str>),
+    ShortestGroups(Option<&'a str>),
+}
+
+impl<'a> PathSelectorKind<'a> {
+    pub fn format(&self) -> String {
+        match self {
+            PathSelectorKind::All => "ALL".to_string(),
+            PathSelectorKind::Any(None) => "ANY".to_string(),
+            PathSelectorKind::Any(Some(k)) => format!("ANY {}", k),
+            PathSelectorKind::AllShortest => "ALL SHORTEST".to_string(),
+            PathSelectorKind::AnyShortest => "ANY SHORTEST".to_string(),
+            PathSelectorKind::Shortest(None) => "SHORTEST".to_string(),
+            PathSelectorKind::Shortest(Some(k)) => format!("SHORTEST {}", k),
+            PathSelectorKind::ShortestGroups(None) => "SHORTEST GROUPS".to_string(),
+            PathSelectorKind::ShortestGroups(Some(k)) => format!("SHORTEST {} GROUPS", k),
+        }
+    }
+
+    fn parse_shortest(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                keyword("SHORTEST"),
+                optional(preceded(multispace1, digit1)),
+                optional(preceded(
+                    multispace1,
+                    alt((keyword("GROUPS"), keyword("GROUP"))),
+                )),
+            )),
+            |(_, k, groups)| match (k, groups) {
+                (k, Some(_)) => PathSelectorKind::ShortestGroups(k),
+                (k, None) => PathSelectorKind::Shortest(k),
+            },
+        )(input)
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        alt((
+            map(
+                tuple((keyword("ALL"), multispace1, keyword("SHORTEST"))),
+                |_| PathSelectorKind::AllShortest,
+            ),
+            map(
+                tuple((keyword("ANY"), multispace1, keyword("SHORTEST"))),
+                |_| PathSelectorKind::AnyShortest,
+            ),
+            map(
+                preceded(keyword("ANY"), optional(preceded(multispace1, digit1))),
+                PathSelectorKind::Any,
+            ),
+            map(keyword("ALL"), |_| PathSelectorKind::All),
+            Self::parse_shortest,
+        ))(input)
+    }
+}
+
+// The `PATH` or `PATHS` keyword after the selector is optional and kept as
+// written.
+#[derive(Debug, PartialEq)]
+pub struct PathSelector<'a> {
+    pub kind: PathSelectorKind<'a>,
+    pub path: Option<&'static str>,
+}
+
+impl<'a> PathSelector<'a> {
+    pub fn format(&self) -> String {
+        std::iter::once(self.kind.format())
+            .chain(self.path.map(|p| p.to_string()))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                PathSelectorKind::parse,
+                optional(preceded(multispace1, parse_keyword_of(&["PATHS", "PATH"]))),
+            )),
+            |(kind, path)| PathSelector { kind, path },
+        )(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum MatchMode {
+    DifferentRelationships,
+    RepeatableElements,
+}
+
+impl MatchMode {
+    pub fn format(&self) -> String {
+        match self {
+            MatchMode::DifferentRelationships => "DIFFERENT RELATIONSHIPS",
+            MatchMode::RepeatableElements => "REPEATABLE ELEMENTS",
+        }
+        .to_string()
+    }
+
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        alt((
+            map(
+                tuple((
+                    keyword("DIFFERENT"),
+                    multispace1,
+                    alt((keyword("RELATIONSHIPS"), keyword("RELATIONSHIP"))),
+                )),
+                |_| MatchMode::DifferentRelationships,
+            ),
+            map(
+                tuple((
+                    keyword("REPEATABLE"),
+                    multispace1,
+                    alt((keyword("ELEMENTS"), keyword("ELEMENT"))),
+                )),
+                |_| MatchMode::RepeatableElements,
+            ),
+        ))(input)
+    }
+}
 
 #[derive(Debug, PartialEq)]
 pub struct Pattern<'a>(pub Vec<Entity<'a>>);
 
 impl<'a> Pattern<'a> {
     pub fn format(&self) -> String {
+        self.format_with(&FormatOptions::default())
+    }
+
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        self.0
+            .iter()
+            .map(|e| e.format_with(options))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        let (input, first) = Entity::parse(input)?;
+        let (input, rest) = many0(preceded(multispace0, Entity::parse))(input)?;
+        Ok((input, Pattern(std::iter::once(first).chain(rest).collect())))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum AnonymousPatternPart<'a> {
+    Pattern(Pattern<'a>),
+    ShortestPath(ShortestPath<'a>),
+}
+
+impl<'a> AnonymousPatternPart<'a> {
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        match self {
+            AnonymousPatternPart::Pattern(p) => p.format_with(options),
+            AnonymousPatternPart::ShortestPath(s) => s.format_with(options),
+        }
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        alt((
+            map(ShortestPath::parse, AnonymousPatternPart::ShortestPath),
+            map(Pattern::parse, AnonymousPatternPart::Pattern),
+        ))(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PatternPart<'a> {
+    pub variable: Option<&'a str>,
+    pub selector: Option<PathSelector<'a>>,
+    pub pattern: AnonymousPatternPart<'a>,
+}
+
+impl<'a> PatternPart<'a> {
+    pub fn format(&self) -> String {
+        self.format_with(&FormatOptions::default())
+    }
+
+    pub fn format_with(&self, options: &FormatOptions) -> String {
         format!(
-            "{}",
-            self.0
-                .iter()
-                .map(|e| e.format())
-                .collect::<Vec<_>>()
-                .join(" ")
+            "{}{}{}",
+            self.variable
+                .map(|v| format!("{} = ", v))
+                .unwrap_or_default(),
+            self.selector
+                .as_ref()
+                .map(|s| format!("{} ", s.format()))
+                .unwrap_or_default(),
+            self.pattern.format_with(options)
         )
     }
 
     pub fn parse(input: &'a str) -> IResult<&str, Self> {
-        map(many1(Entity::parse), Pattern)(input)
+        map(
+            tuple((
+                optional(terminated(
+                    token::parse,
+                    tuple((multispace0, tag("="), multispace0)),
+                )),
+                optional(terminated(PathSelector::parse, multispace1)),
+                AnonymousPatternPart::parse,
+            )),
+            |(variable, selector, pattern)| PatternPart {
+                variable,
+                selector,
+                pattern,
+            },
+        )(input)
+    }
+
+    pub fn format_list(parts: &[PatternPart], options: &FormatOptions) -> String {
+        let parts = parts
+            .iter()
+            .map(|p| p.format_with(options))
+            .collect::<Vec<_>>();
+        let line = parts.join(", ");
+        if line.len() <= MAX_LINE_WIDTH {
+            return line;
+        }
+        parts.join(",\n")
+    }
+
+    pub fn parse_list(input: &'a str) -> IResult<&'a str, Vec<PatternPart<'a>>> {
+        separated_list1(tuple((space0, tag(","), multispace0)), Self::parse)(input)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        expression::Expression, key_value::KeyValue, label::Label, line::Line, node::Node,
-        literal::map::Map, relationship::Relationship,
+        expression::Expression,
+        key_value::KeyValue,
+        label::{Label, LabelExpression},
+        line::Line,
+        literal::map::Map,
+        node::Node,
+        relationship::Relationship,
     };
 
     use super::*;
@@ -38,7 +363,7 @@ mod tests {
             Pattern(vec![
                 Entity::Node(Node {
                     variable: Some("var1"),
-                    labels: vec![Label("ALabel")],
+                    labels: Some(LabelExpression::Label(Label("ALabel"))),
                     properties: Some(Map(vec![KeyValue {
                         key: "a",
                         value: Expression::decimal_int("10"),
@@ -46,17 +371,19 @@ mod tests {
                 }),
                 Entity::Relationship(Relationship {
                     variable: Some("rel1"),
-                    labels: vec![Label("ARelationship")],
+                    labels: Some(LabelExpression::Label(Label("ARelationship"))),
+                    length: None,
                     properties: Some(Map(vec![KeyValue {
                         key: "r",
                         value: Expression::decimal_int("15"),
                     }])),
+                    bracketed: true,
                     right_line: Line("-"),
                     left_line: Line("<-"),
                 }),
                 Entity::Node(Node {
                     variable: Some("var2"),
-                    labels: vec![Label("BLabel")],
+                    labels: Some(LabelExpression::Label(Label("BLabel"))),
                     properties: Some(Map(vec![KeyValue {
                         key: "b",
                         value: Expression::decimal_int("20"),
@@ -70,4 +397,66 @@ mod tests {
         );
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn parse_pattern_part_named() {
+        let expected = Ok((
+            " data",
+            PatternPart {
+                variable: Some("p"),
+                selector: None,
+                pattern: AnonymousPatternPart::Pattern(Pattern(vec![Entity::Node(Node {
+                    variable: Some("a"),
+                    labels: None,
+                    properties: None,
+                })])),
+            },
+        ));
+        let actual = PatternPart::parse("p=(a) data");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_pattern_part_list_long() {
+        let expected = "p = (person:Person) -[:LIVES_IN]-> (city:City),\n(city) -[:IN_COUNTRY]-> (country:Country)";
+        let actual = PatternPart::format_list(
+            &PatternPart::parse_list(
+                "p = (person:Person)-[:LIVES_IN]->(city:City), (city)-[:IN_COUNTRY]->(country:Country)",
+            )
+            .unwrap()
+            .1,
+            &FormatOptions::default(),
+        );
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_quantified_path_patterns() {
+        let format = |pattern| PatternPart::parse(pattern).unwrap().1.format();
+        assert_eq!(
+            "(a) ((x) -[:NEXT]-> (y)){2,5} (b)",
+            format("(a)((x)-[:NEXT]->(y)){ 2 , 5 }(b)")
+        );
+        assert_eq!("(a) -[:R]->+ (b)", format("(a)-[:R]->+(b)"));
+        assert_eq!(
+            "((a) -[r:ROAD]-> (b) WHERE r.length < 10){,3}",
+            format("((a)-[r:ROAD]->(b) where r.length < 10){,3}")
+        );
+    }
+
+    #[test]
+    fn format_path_selectors() {
+        let format = |pattern| PatternPart::parse(pattern).unwrap().1.format();
+        assert_eq!(
+            "p = SHORTEST 3 PATHS (a) -[:R]->* (b)",
+            format("p = shortest 3 paths (a)-[:R]->*(b)")
+        );
+        assert_eq!("ALL SHORTEST (a) --+ (b)", format("ALL SHORTEST (a)--+(b)"));
+        assert_eq!("SHORTEST (a) --+ (b)", format("shortest (a)--+(b)"));
+        assert_eq!(
+            "SHORTEST 2 GROUPS (a) -->{1,} (b)",
+            format("SHORTEST 2 GROUPS (a)-->{1,}(b)")
+        );
+        assert_eq!("ANY PATH (a) -->{2} (b)", format("any path (a)-->{2}(b)"));
+    }
 }
@@ -0,0 +1,147 @@
+use nom::{
+    bytes::complete::tag,
+    character::complete::{multispace0, space0},
+    combinator::{map, recognize},
+    multi::{separated_list0, separated_list1},
+    sequence::{delimited, tuple},
+    IResult,
+};
+
+use crate::{
+    expression::Expression,
+    shared::{indent, optional, MAX_LINE_WIDTH},
+    token,
+};
+
+#[derive(Debug, PartialEq)]
+pub struct ProcedureInvocation<'a> {
+    name: &'a str,
+    arguments: Option<Vec<Expression<'a>>>,
+}
+
+fn parse_procedure_name(input: &str) -> IResult<&str, &str> {
+    recognize(separated_list1(tag("."), token::parse))(input)
+}
+
+fn parse_arguments<'a>(input: &'a str) -> IResult<&str, Vec<Expression<'a>>> {
+    delimited(
+        tuple((tag("("), multispace0)),
+        separated_list0(tuple((space0, tag(","), multispace0)), Expression::parse),
+        tuple((multispace0, tag(")"))),
+    )(input)
+}
+
+impl<'a> ProcedureInvocation<'a> {
+    fn arguments_str(&self, prefix: &str) -> String {
+        let arguments = match &self.arguments {
+            Some(arguments) => arguments.iter().map(|a| a.format()).collect::<Vec<_>>(),
+            None => return "".to_string(),
+        };
+
+        let single_line = format!("{}{}({})", prefix, self.name, arguments.join(", "));
+        if single_line.len() <= MAX_LINE_WIDTH {
+            return format!("({})", arguments.join(", "));
+        }
+
+        format!("(\n{}\n)", indent(&arguments.join(",\n")))
+    }
+
+    pub fn format(&self) -> String {
+        self.format_prefixed("")
+    }
+
+    // The prefix is the keyword the invocation follows on its line, such as
+    // `CALL `, and counts towards the line width.
+    pub fn format_prefixed(&self, prefix: &str) -> String {
+        format!("{}{}{}", prefix, self.name, self.arguments_str(prefix))
+    }
+
+    pub fn parse_explicit(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((parse_procedure_name, space0, parse_arguments)),
+            |(name, _, arguments)| ProcedureInvocation {
+                name,
+                arguments: Some(arguments),
+            },
+        )(input)
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                parse_procedure_name,
+                optional(tuple((space0, parse_arguments))),
+            )),
+            |(name, arguments)| ProcedureInvocation {
+                name,
+                arguments: arguments.map(|(_, arguments)| arguments),
+            },
+        )(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_procedure_invocation_explicit() {
+        let expected = Ok((
+            " data",
+            ProcedureInvocation {
+                name: "db.index.fulltext.queryNodes",
+                arguments: Some(vec![
+                    Expression::string("'people'"),
+                    Expression::parameter("$query"),
+                ]),
+            },
+        ));
+        let actual = ProcedureInvocation::parse_explicit(
+            "db.index.fulltext.queryNodes('people',$query) data",
+        );
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_procedure_invocation_implicit() {
+        let expected = Ok((
+            " data",
+            ProcedureInvocation {
+                name: "db.labels",
+                arguments: None,
+            },
+        ));
+        let actual = ProcedureInvocation::parse("db.labels data");
+        assert_eq!(expected, actual);
+        assert!(ProcedureInvocation::parse_explicit("db.labels data").is_err());
+    }
+
+    #[test]
+    fn format_procedure_invocation_long() {
+        let expected = "apoc.periodic.iterate(\n  'MATCH (p:Person) RETURN p',\n  'SET p.visited = true',\n  {batchSize: 1000, parallel: FALSE}\n)";
+        let actual = ProcedureInvocation::parse(
+            "apoc.periodic.iterate('MATCH (p:Person) RETURN p', 'SET p.visited = true', {batchSize: 1000, parallel: false})",
+        )
+        .unwrap()
+        .1
+        .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_procedure_invocation_prefixed() {
+        let invocation = ProcedureInvocation::parse(
+            "db.index.fulltext.queryNodes('people', $query, {limit: 1000, offset: 10000})",
+        )
+        .unwrap()
+        .1;
+        assert_eq!(
+            "db.index.fulltext.queryNodes('people', $query, {limit: 1000, offset: 10000})",
+            invocation.format()
+        );
+        assert_eq!(
+            "CALL db.index.fulltext.queryNodes(\n  'people',\n  $query,\n  {limit: 1000, offset: 10000}\n)",
+            invocation.format_prefixed("CALL ")
+        );
+    }
+}
@@ -1,13 +1,19 @@
 use nom::{
-    bytes::complete::{tag, tag_no_case},
-    character::complete::{space0, space1},
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{multispace0, multispace1, space0, space1},
     combinator::{map, recognize},
-    multi::{many0, separated_list0},
-    sequence::tuple,
+    multi::{separated_list0, separated_list1},
+    sequence::{terminated, tuple},
     IResult,
 };
 
-use crate::{expression::Expression, shared::optional, symbolic_name};
+use crate::{
+    expression::Expression,
+    format_options::FormatOptions,
+    shared::{join_at, keyword, optional},
+    symbolic_name,
+};
 
 #[derive(Debug, PartialEq)]
 pub enum Order {
@@ -17,6 +23,29 @@ pub enum Order {
     Desc,
 }
 
+impl Order {
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        match self {
+            Order::Ascending if options.normalize_order => "ASC",
+            Order::Descending if options.normalize_order => "DESC",
+            Order::Ascending => "ASCENDING",
+            Order::Asc => "ASC",
+            Order::Descending => "DESCENDING",
+            Order::Desc => "DESC",
+        }
+        .to_string()
+    }
+
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        alt((
+            map(keyword("ASCENDING"), |_| Order::Ascending),
+            map(keyword("ASC"), |_| Order::Asc),
+            map(keyword("DESCENDING"), |_| Order::Descending),
+            map(keyword("DESC"), |_| Order::Desc),
+        ))(input)
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub struct ProjectionItem<'a> {
     pub expression: Expression<'a>,
@@ -31,13 +60,21 @@ impl<'a> ProjectionItem<'a> {
     }
 
     pub fn format(&self) -> String {
-        format!("{}{}", self.expression.format(), self.variable_str())
+        self.format_at(&FormatOptions::default(), 0)
+    }
+
+    pub fn format_at(&self, options: &FormatOptions, column: usize) -> String {
+        format!(
+            "{}{}",
+            self.expression.format_at(options, column),
+            self.variable_str()
+        )
     }
 
     pub fn parse(input: &'a str) -> IResult<&str, Self> {
         let (input, expression) = Expression::parse(input)?;
         let (input, variable) = optional(map(
-            tuple((space1, tag_no_case("AS"), space1, symbolic_name::parse)),
+            tuple((space0, keyword("AS"), space1, symbolic_name::parse)),
             |(_, _, _, variable)| variable,
         ))(input)?;
         Ok((
@@ -57,8 +94,30 @@ pub struct SortItem<'a> {
 }
 
 impl<'a> SortItem<'a> {
-    pub fn parse(_input: &'a str) -> IResult<&str, Self> {
-        todo!()
+    fn order_str(&self, options: &FormatOptions) -> String {
+        self.order
+            .as_ref()
+            .map(|o| format!(" {}", o.format_with(options)))
+            .unwrap_or_default()
+    }
+
+    pub fn format(&self) -> String {
+        self.format_with(&FormatOptions::default())
+    }
+
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        format!(
+            "{}{}",
+            self.expression.format_with(options),
+            self.order_str(options)
+        )
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((Expression::parse, space0, optional(Order::parse))),
+            |(expression, _, order)| SortItem { expression, order },
+        )(input)
     }
 }
 
@@ -73,74 +132,130 @@ pub struct ProjectionBody<'a> {
 }
 
 fn parse_distinct<'a>(input: &'a str) -> IResult<&str, Option<&str>> {
-    optional(tag_no_case("DISTINCT"))(input)
+    optional(terminated(keyword("DISTINCT"), multispace1))(input)
 }
 
 fn parse_wild_card<'a>(input: &'a str) -> IResult<&str, Option<&str>> {
-    optional(tag("*"))(input)
+    optional(terminated(
+        tag("*"),
+        tuple((space0, optional(tuple((tag(","), space0))))),
+    ))(input)
 }
 
 fn parse_order_by<'a>(input: &'a str) -> IResult<&str, Option<&str>> {
     optional(recognize(tuple((
-        tag_no_case("ORDER"),
-        space1,
-        tag_no_case("BY"),
+        multispace0,
+        keyword("ORDER"),
+        multispace1,
+        keyword("BY"),
+        multispace1,
     ))))(input)
 }
 
 fn parse_projection_items<'a>(input: &'a str) -> IResult<&str, Vec<ProjectionItem<'a>>> {
-    separated_list0(tag(", "), ProjectionItem::parse)(input)
+    separated_list0(tuple((space0, tag(","), space0)), ProjectionItem::parse)(input)
 }
 
 fn parse_sort_items<'a>(input: &'a str) -> IResult<&str, Vec<SortItem<'a>>> {
-    if let Ok((input, None)) = parse_order_by(input) {
+    let (input, order_by) = parse_order_by(input)?;
+    if order_by.is_none() {
         return Ok((input, vec![]));
     }
 
-    map(many0(tuple((SortItem::parse, space1))), |result| {
-        result.into_iter().map(|(item, _)| item).collect()
-    })(input)
+    separated_list1(tuple((space0, tag(","), multispace0)), SortItem::parse)(input)
 }
 
 fn parse_skip_item<'a>(input: &'a str) -> IResult<&'a str, Option<Expression>> {
     optional(map(
-        tuple((tag_no_case("SKIP"), space1, Expression::parse)),
-        |(_, _, result)| result,
+        tuple((multispace0, keyword("SKIP"), multispace1, Expression::parse)),
+        |(_, _, _, result)| result,
     ))(input)
 }
 
 fn parse_limit_item<'a>(input: &'a str) -> IResult<&'a str, Option<Expression>> {
     optional(map(
-        tuple((tag_no_case("LIMIT"), space1, Expression::parse)),
-        |(_, _, result)| result,
+        tuple((
+            multispace0,
+            keyword("LIMIT"),
+            multispace1,
+            Expression::parse,
+        )),
+        |(_, _, _, result)| result,
     ))(input)
 }
 
 impl<'a> ProjectionBody<'a> {
     fn distinct_str(&self) -> String {
         if self.distinct {
-            return "DISTINCT".to_string();
+            return "DISTINCT ".to_string();
         }
         "".to_string()
     }
 
-    fn wild_card_str(&self) -> String {
-        if self.wild_card {
-            return "*".to_string();
-        }
-        "".to_string()
+    fn projection_items_str(&self, options: &FormatOptions, column: usize) -> String {
+        let wild_card = match (self.wild_card, self.projection_items.is_empty()) {
+            (false, _) => "",
+            (true, true) => "*",
+            (true, false) => "*, ",
+        };
+        format!(
+            "{}{}",
+            wild_card,
+            join_at(
+                &self.projection_items,
+                ", ",
+                column + wild_card.len(),
+                |p, column| p.format_at(options, column)
+            )
+        )
     }
 
-    pub fn format(&self) -> String {
+    fn sort_expressions_str(&self, options: &FormatOptions) -> String {
+        if self.sort_expressions.is_empty() {
+            return "".to_string();
+        }
+
         format!(
-            "{}{}{}",
-            self.distinct_str(),
-            self.wild_card_str(),
-            self.projection_items
+            "\nORDER BY {}",
+            self.sort_expressions
                 .iter()
-                .map(|p| p.format())
+                .map(|s| s.format_with(options))
                 .collect::<Vec<_>>()
-                .join(" ")
+                .join(", ")
+        )
+    }
+
+    fn skip_expression_str(&self, options: &FormatOptions) -> String {
+        self.skip_expression
+            .as_ref()
+            .map(|e| format!("\nSKIP {}", e.format_at(options, "SKIP ".len())))
+            .unwrap_or_default()
+    }
+
+    fn limit_expression_str(&self, options: &FormatOptions) -> String {
+        self.limit_expression
+            .as_ref()
+            .map(|e| format!("\nLIMIT {}", e.format_at(options, "LIMIT ".len())))
+            .unwrap_or_default()
+    }
+
+    pub fn format(&self) -> String {
+        self.format_with(&FormatOptions::default())
+    }
+
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        self.format_at(options, 0)
+    }
+
+    pub fn format_at(&self, options: &FormatOptions, column: usize) -> String {
+        let distinct = self.distinct_str();
+        format!(
+            "{}{}{}{}{}",
+            distinct,
+            self.projection_items_str(options, column + distinct.len()),
+            self.sort_expressions_str(options),
+            self.skip_expression_str(options),
+            self.limit_expression_str(options)
         )
     }
 
@@ -148,9 +263,9 @@ impl<'a> ProjectionBody<'a> {
         let (input, distinct) = parse_distinct(input)?;
         let (input, wild_card) = parse_wild_card(input)?;
         let (input, projection_items) = parse_projection_items(input)?;
-        //let (input, sort_items) = parse_sort_items(input)?;
-        //let (input, skip_item) = parse_skip_item(input)?;
-        //let (input, limit_item) = parse_limit_item(input)?;
+        let (input, sort_items) = parse_sort_items(input)?;
+        let (input, skip_item) = parse_skip_item(input)?;
+        let (input, limit_item) = parse_limit_item(input)?;
 
         Ok((
             input,
@@ -158,10 +273,88 @@ impl<'a> ProjectionBody<'a> {
                 distinct: distinct.is_some(),
                 wild_card: wild_card.is_some(),
                 projection_items,
+                sort_expressions: sort_items,
+                limit_expression: limit_item,
+                skip_expression: skip_item,
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_projection_body_distinct() {
+        let expected = Ok((
+            "",
+            ProjectionBody {
+                distinct: true,
+                wild_card: false,
+                projection_items: vec![
+                    ProjectionItem {
+                        expression: Expression::variable("a"),
+                        variable: None,
+                    },
+                    ProjectionItem {
+                        expression: Expression::variable("b"),
+                        variable: Some("c"),
+                    },
+                ],
                 sort_expressions: vec![],
-                limit_expression: None,
                 skip_expression: None,
+                limit_expression: None,
             },
-        ))
+        ));
+        let actual = ProjectionBody::parse("DISTINCT a ,b AS c");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_projection_body() {
+        let expected = "DISTINCT *, n.name AS name, n.age";
+        let actual = ProjectionBody::parse("distinct *,n.name as name,   n.age")
+            .unwrap()
+            .1
+            .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_sort_item() {
+        let expected = Ok((
+            ", b",
+            SortItem {
+                expression: Expression::variable("a"),
+                order: Some(Order::Descending),
+            },
+        ));
+        let actual = SortItem::parse("a descending, b");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_sort_item_normalize_order() {
+        let options = FormatOptions {
+            normalize_order: true,
+            ..Default::default()
+        };
+        let expected = "a ASC";
+        let actual = SortItem::parse("a Ascending")
+            .unwrap()
+            .1
+            .format_with(&options);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_projection_body_order_skip_limit() {
+        let expected = "p\nORDER BY p.name, p.age DESC\nSKIP 5\nLIMIT 1";
+        let actual = ProjectionBody::parse("p order by p.name,p.age desc skip 5 limit 1")
+            .unwrap()
+            .1
+            .format();
+        assert_eq!(expected, actual);
     }
 }
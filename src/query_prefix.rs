@@ -0,0 +1,119 @@
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{digit1, multispace1, space0},
+    combinator::{map, recognize},
+    multi::many0,
+    sequence::{preceded, tuple},
+    IResult,
+};
+
+use crate::{
+    shared::{keyword, optional},
+    token,
+};
+
+#[derive(Debug, PartialEq)]
+pub struct CypherOption<'a> {
+    key: &'a str,
+    value: &'a str,
+}
+
+impl<'a> CypherOption<'a> {
+    pub fn format(&self) -> String {
+        format!("{}={}", self.key, self.value)
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((token::parse, space0, tag("="), space0, token::parse)),
+            |(key, _, _, _, value)| CypherOption { key, value },
+        )(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum QueryPrefix<'a> {
+    Explain,
+    Profile,
+    Cypher {
+        version: Option<&'a str>,
+        options: Vec<CypherOption<'a>>,
+    },
+}
+
+impl<'a> QueryPrefix<'a> {
+    pub fn format(&self) -> String {
+        match self {
+            QueryPrefix::Explain => "EXPLAIN".to_string(),
+            QueryPrefix::Profile => "PROFILE".to_string(),
+            QueryPrefix::Cypher { version, options } => std::iter::once("CYPHER".to_string())
+                .chain(version.map(|v| v.to_string()))
+                .chain(options.iter().map(|o| o.format()))
+                .collect::<Vec<_>>()
+                .join(" "),
+        }
+    }
+
+    fn parse_version(input: &'a str) -> IResult<&str, &str> {
+        recognize(tuple((digit1, optional(tuple((tag("."), digit1))))))(input)
+    }
+
+    fn parse_cypher(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                keyword("CYPHER"),
+                optional(preceded(multispace1, Self::parse_version)),
+                many0(preceded(multispace1, CypherOption::parse)),
+            )),
+            |(_, version, options)| QueryPrefix::Cypher { version, options },
+        )(input)
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        alt((
+            map(keyword("EXPLAIN"), |_| QueryPrefix::Explain),
+            map(keyword("PROFILE"), |_| QueryPrefix::Profile),
+            Self::parse_cypher,
+        ))(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_query_prefix_cypher() {
+        let expected = Ok((
+            " MATCH",
+            QueryPrefix::Cypher {
+                version: Some("5"),
+                options: vec![
+                    CypherOption {
+                        key: "runtime",
+                        value: "parallel",
+                    },
+                    CypherOption {
+                        key: "planner",
+                        value: "cost",
+                    },
+                ],
+            },
+        ));
+        let actual = QueryPrefix::parse("cypher 5 runtime = parallel planner=cost MATCH");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_query_prefix() {
+        assert_eq!("PROFILE", QueryPrefix::parse("profile").unwrap().1.format());
+        assert_eq!(
+            "CYPHER runtime=slotted",
+            QueryPrefix::parse("CYPHER runtime=slotted")
+                .unwrap()
+                .1
+                .format()
+        );
+    }
+}
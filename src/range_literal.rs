@@ -0,0 +1,83 @@
+use nom::{
+    bytes::complete::tag,
+    character::complete::{digit1, space0},
+    combinator::{map, opt},
+    sequence::{preceded, tuple},
+    IResult,
+};
+
+#[derive(Debug, PartialEq)]
+pub enum RangeLiteral<'a> {
+    Unbounded,
+    Exact(&'a str),
+    Range(Option<&'a str>, Option<&'a str>),
+}
+
+impl<'a> RangeLiteral<'a> {
+    pub fn format(&self) -> String {
+        match self {
+            RangeLiteral::Unbounded => "*".to_string(),
+            RangeLiteral::Exact(length) => format!("*{}", length),
+            RangeLiteral::Range(min, max) => {
+                format!("*{}..{}", min.unwrap_or(""), max.unwrap_or(""))
+            }
+        }
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            preceded(
+                tuple((tag("*"), space0)),
+                tuple((
+                    opt(digit1),
+                    opt(preceded(tuple((space0, tag(".."), space0)), opt(digit1))),
+                )),
+            ),
+            |range| match range {
+                (None, None) | (None, Some(None)) => RangeLiteral::Unbounded,
+                (Some(length), None) => RangeLiteral::Exact(length),
+                (min, Some(max)) => RangeLiteral::Range(min, max),
+            },
+        )(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_range_literal() {
+        assert_eq!(
+            Ok(("]", RangeLiteral::Unbounded)),
+            RangeLiteral::parse("*]")
+        );
+        assert_eq!(
+            Ok(("]", RangeLiteral::Unbounded)),
+            RangeLiteral::parse("*..]")
+        );
+        assert_eq!(
+            Ok(("]", RangeLiteral::Exact("3"))),
+            RangeLiteral::parse("*3]")
+        );
+        assert_eq!(
+            Ok(("]", RangeLiteral::Range(Some("1"), Some("3")))),
+            RangeLiteral::parse("* 1 .. 3]")
+        );
+        assert_eq!(
+            Ok(("]", RangeLiteral::Range(None, Some("5")))),
+            RangeLiteral::parse("*..5]")
+        );
+        assert_eq!(
+            Ok(("]", RangeLiteral::Range(Some("2"), None))),
+            RangeLiteral::parse("*2..]")
+        );
+    }
+
+    #[test]
+    fn format_range_literal() {
+        let expected = "*1..3";
+        let actual = RangeLiteral::parse("* 1 .. 3").unwrap().1.format();
+        assert_eq!(expected, actual);
+    }
+}
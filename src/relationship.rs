@@ -1,17 +1,27 @@
 use nom::{
     bytes::complete::tag,
-    combinator::opt,
-    sequence::{delimited, tuple},
+    character::complete::space0,
+    combinator::{opt, verify},
+    sequence::{delimited, preceded, tuple},
     IResult,
 };
 
-use crate::{label::Label, line::Line, literal::map::Map, token};
+use crate::{
+    format_options::{FormatOptions, RelationshipBrackets},
+    label::LabelExpression,
+    line::Line,
+    literal::map::Map,
+    range_literal::RangeLiteral,
+    token,
+};
 
 #[derive(Debug, PartialEq)]
 pub struct Relationship<'a> {
     pub variable: Option<&'a str>,
-    pub labels: Vec<Label<'a>>,
+    pub labels: Option<LabelExpression<'a>>,
+    pub length: Option<RangeLiteral<'a>>,
     pub properties: Option<Map<'a>>,
+    pub bracketed: bool,
     pub right_line: Line<'a>,
     pub left_line: Line<'a>,
 }
@@ -23,48 +33,103 @@ impl<'a> Relationship<'a> {
             .unwrap_or("".to_string())
     }
 
-    fn labels_str(&self) -> String {
-        if self.labels.len() == 0 {
-            return "".to_string();
-        }
-
-        self.labels.iter().map(|l| l.format()).collect()
+    fn labels_str(&self, options: &FormatOptions) -> String {
+        self.labels
+            .as_ref()
+            .map(|l| format!(":{}", l.format_with(options)))
+            .unwrap_or_default()
     }
 
-    fn properties_str(&self) -> String {
-        self.properties
+    fn length_str(&self) -> String {
+        self.length
             .as_ref()
-            .clone()
-            .map(|p| p.format())
+            .map(|l| l.format())
             .unwrap_or("".to_string())
     }
 
+    fn properties_str(&self) -> String {
+        match &self.properties {
+            Some(p)
+                if self.variable.is_none() && self.labels.is_none() && self.length.is_none() =>
+            {
+                p.format()
+            }
+            Some(p) => format!(" {}", p.format()),
+            None => "".to_string(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.variable.is_none()
+            && self.labels.is_none()
+            && self.length.is_none()
+            && self.properties.is_none()
+    }
+
+    fn brackets(&self, options: &FormatOptions) -> bool {
+        if !self.is_empty() {
+            return true;
+        }
+        match options.relationship_brackets {
+            RelationshipBrackets::Keep => self.bracketed,
+            RelationshipBrackets::Expand => true,
+            RelationshipBrackets::Collapse => false,
+        }
+    }
+
     pub fn format(&self) -> String {
+        self.format_with(&FormatOptions::default())
+    }
+
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        if !self.brackets(options) {
+            return format!("{}{}", self.left_line.format(), self.right_line.format());
+        }
+
         format!(
-            "{}[{}{}{}]{}",
-            self.right_line.format(),
+            "{}[{}{}{}{}]{}",
+            self.left_line.format(),
             self.variable_str(),
-            self.labels_str(),
+            self.labels_str(options),
+            self.length_str(),
             self.properties_str(),
-            self.left_line.format()
+            self.right_line.format()
         )
     }
 
+    // A relationship has exactly one type, so `:A:B` is rejected; only `|`
+    // and the legacy `|:` separate alternatives.
+    pub fn parse_types(input: &'a str) -> IResult<&'a str, LabelExpression<'a>> {
+        verify(LabelExpression::parse, |types: &LabelExpression| {
+            !types.has_colon_conjunction()
+        })(input)
+    }
+
     pub fn parse(input: &'a str) -> IResult<&str, Self> {
         let (input, left_line) = Line::parse(input)?;
-        let (input, (variable, labels, properties)) = delimited(
-            tag("["),
-            tuple((opt(token::parse), opt(Label::parse_many1), opt(Map::parse))),
-            tag("]"),
-        )(input)?;
+        let (input, detail) = opt(delimited(
+            tuple((tag("["), space0)),
+            tuple((
+                opt(token::parse),
+                opt(Self::parse_types),
+                opt(preceded(space0, RangeLiteral::parse)),
+                opt(preceded(space0, Map::parse)),
+            )),
+            tuple((space0, tag("]"))),
+        ))(input)?;
         let (input, right_line) = Line::parse(input)?;
 
+        let bracketed = detail.is_some();
+        let (variable, labels, length, properties) = detail.unwrap_or((None, None, None, None));
+
         Ok((
             input,
             Relationship {
                 variable,
-                labels: labels.unwrap_or(vec![]),
+                labels,
+                length,
                 properties,
+                bracketed,
                 right_line,
                 left_line,
             },
@@ -74,7 +139,7 @@ impl<'a> Relationship<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{expression::Expression, key_value::KeyValue, value::Value};
+    use crate::{expression::Expression, key_value::KeyValue, label::Label};
 
     use super::*;
 
@@ -84,8 +149,10 @@ mod tests {
             " data",
             Relationship {
                 variable: None,
-                labels: vec![],
+                labels: None,
+                length: None,
                 properties: None,
+                bracketed: true,
                 right_line: Line("-"),
                 left_line: Line("-"),
             },
@@ -101,8 +168,10 @@ mod tests {
             " data",
             Relationship {
                 variable: None,
-                labels: vec![Label("ALabel")],
+                labels: Some(LabelExpression::Label(Label("ALabel"))),
+                length: None,
                 properties: None,
+                bracketed: true,
                 right_line: Line("-"),
                 left_line: Line("-"),
             },
@@ -118,28 +187,41 @@ mod tests {
             " data",
             Relationship {
                 variable: None,
-                labels: vec![Label("ALabel"), Label("BLabel")],
+                labels: Some(LabelExpression::Or(vec![
+                    LabelExpression::Label(Label("ALabel")),
+                    LabelExpression::Label(Label("BLabel")),
+                ])),
+                length: None,
                 properties: None,
+                bracketed: true,
                 right_line: Line("-"),
                 left_line: Line("-"),
             },
         ));
 
-        let actual = Relationship::parse("-[:ALabel:BLabel]- data");
+        let actual = Relationship::parse("-[:ALabel|BLabel]- data");
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn parse_relationship_colon_separated_types() {
+        assert!(Relationship::parse("-[:ALabel:BLabel]- data").is_err());
+        assert!(Relationship::parse("-[:ALabel|BLabel:CLabel]- data").is_err());
+    }
+
     #[test]
     fn parse_relationship_properties() {
         let expected = Ok((
             " data",
             Relationship {
                 variable: None,
-                labels: vec![],
+                labels: None,
+                length: None,
                 properties: Some(Map(vec![KeyValue {
                     key: "some_key",
                     value: Expression::decimal_int("10"),
                 }])),
+                bracketed: true,
                 right_line: Line("-"),
                 left_line: Line("-"),
             },
@@ -155,8 +237,10 @@ mod tests {
             " data",
             Relationship {
                 variable: Some("myVar"),
-                labels: vec![],
+                labels: None,
+                length: None,
                 properties: None,
+                bracketed: true,
                 right_line: Line("-"),
                 left_line: Line("-"),
             },
@@ -172,8 +256,10 @@ mod tests {
             " data",
             Relationship {
                 variable: Some("myVar"),
-                labels: vec![Label("ALabel")],
+                labels: Some(LabelExpression::Label(Label("ALabel"))),
+                length: None,
                 properties: None,
+                bracketed: true,
                 right_line: Line("-"),
                 left_line: Line("-"),
             },
@@ -183,17 +269,105 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn format_relationship_directed() {
+        let expected = "<-[r:ALabel]-";
+        let actual = Relationship::parse("<-[r:ALabel]-").unwrap().1.format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_relationship_types_length() {
+        let expected = Ok((
+            " data",
+            Relationship {
+                variable: Some("r"),
+                labels: Some(LabelExpression::Or(vec![
+                    LabelExpression::Label(Label("KNOWS")),
+                    LabelExpression::Label(Label("LIKES")),
+                ])),
+                length: Some(RangeLiteral::Range(Some("1"), Some("3"))),
+                properties: None,
+                bracketed: true,
+                right_line: Line("->"),
+                left_line: Line("-"),
+            },
+        ));
+
+        let actual = Relationship::parse("-[r:KNOWS|:LIKES *1..3]-> data");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_relationship_types_length_properties() {
+        let expected = "-[:A|B|C*..5 {since: 2020}]-";
+        let actual = Relationship::parse("-[ :A | B|C * .. 5{since: 2020} ]-")
+            .unwrap()
+            .1
+            .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_relationship_unbounded() {
+        let expected = "-[*]->";
+        let actual = Relationship::parse("-[*]->").unwrap().1.format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_relationship_bracketless() {
+        let expected = Ok((
+            "(b)",
+            Relationship {
+                variable: None,
+                labels: None,
+                length: None,
+                properties: None,
+                bracketed: false,
+                right_line: Line("->"),
+                left_line: Line("-"),
+            },
+        ));
+
+        let actual = Relationship::parse("-->(b)");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_relationship_brackets() {
+        let expand = FormatOptions {
+            relationship_brackets: RelationshipBrackets::Expand,
+            ..Default::default()
+        };
+        let collapse = FormatOptions {
+            relationship_brackets: RelationshipBrackets::Collapse,
+            ..Default::default()
+        };
+        let bracketless = Relationship::parse("<--").unwrap().1;
+        let empty = Relationship::parse("-[]-").unwrap().1;
+        let typed = Relationship::parse("-[:KNOWS]-").unwrap().1;
+
+        assert_eq!("<--", bracketless.format());
+        assert_eq!("-[]-", empty.format());
+        assert_eq!("<-[]-", bracketless.format_with(&expand));
+        assert_eq!("--", empty.format_with(&collapse));
+        assert_eq!("-[:KNOWS]-", typed.format_with(&collapse));
+    }
+
     #[test]
     fn parse_relationship_variable_single_label_properties() {
         let expected = Ok((
             " data",
             Relationship {
                 variable: Some("myVar"),
-                labels: vec![Label("ALabel")],
+                labels: Some(LabelExpression::Label(Label("ALabel"))),
+                length: None,
                 properties: Some(Map(vec![KeyValue {
                     key: "some_key",
                     value: Expression::decimal_int("10"),
                 }])),
+                bracketed: true,
                 right_line: Line("-"),
                 left_line: Line("-"),
             },
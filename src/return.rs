@@ -3,14 +3,18 @@ use nom::{
     IResult,
 };
 
-use crate::projection_body::ProjectionBody;
+use crate::{format_options::FormatOptions, projection_body::ProjectionBody};
 
 #[derive(Debug, PartialEq)]
 pub struct Return<'a>(ProjectionBody<'a>);
 
 impl<'a> Return<'a> {
     pub fn format(&self) -> String {
-        format!("RETURN {}", self.0.format())
+        self.format_with(&FormatOptions::default())
+    }
+
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        format!("RETURN {}", self.0.format_at(options, "RETURN ".len()))
     }
 
     pub fn parse(input: &'a str) -> IResult<&str, Self> {
@@ -0,0 +1,619 @@
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{multispace0, multispace1, space0},
+    combinator::{map, verify},
+    multi::{many1, separated_list1},
+    sequence::{delimited, preceded, terminated, tuple},
+    IResult,
+};
+
+use crate::{
+    label::LabelExpression,
+    line::Line,
+    literal::map::Map,
+    relationship::Relationship,
+    shared::{
+        if_exists_str, if_not_exists_str, keyword, optional, parse_if_exists, parse_if_not_exists,
+        parse_keyword_of, MAX_LINE_WIDTH,
+    },
+    token,
+};
+
+#[derive(Debug, PartialEq)]
+pub enum IndexKind {
+    Range,
+    Text,
+    Point,
+    Fulltext,
+    Vector,
+}
+
+impl IndexKind {
+    pub fn format(&self) -> String {
+        match self {
+            IndexKind::Range => "RANGE",
+            IndexKind::Text => "TEXT",
+            IndexKind::Point => "POINT",
+            IndexKind::Fulltext => "FULLTEXT",
+            IndexKind::Vector => "VECTOR",
+        }
+        .to_string()
+    }
+
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        alt((
+            map(keyword("RANGE"), |_| IndexKind::Range),
+            map(keyword("TEXT"), |_| IndexKind::Text),
+            map(keyword("POINT"), |_| IndexKind::Point),
+            map(keyword("FULLTEXT"), |_| IndexKind::Fulltext),
+            map(keyword("VECTOR"), |_| IndexKind::Vector),
+        ))(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SchemaPattern<'a> {
+    Node {
+        variable: &'a str,
+        labels: LabelExpression<'a>,
+    },
+    Relationship {
+        variable: &'a str,
+        labels: LabelExpression<'a>,
+    },
+}
+
+impl<'a> SchemaPattern<'a> {
+    pub fn format(&self) -> String {
+        match self {
+            SchemaPattern::Node { variable, labels } => {
+                format!("({}:{})", variable, labels.format())
+            }
+            SchemaPattern::Relationship { variable, labels } => {
+                format!("()-[{}:{}]-()", variable, labels.format())
+            }
+        }
+    }
+
+    fn parse_node(input: &'a str) -> IResult<&str, Self> {
+        map(
+            delimited(
+                tuple((tag("("), space0)),
+                tuple((token::parse, LabelExpression::parse)),
+                tuple((space0, tag(")"))),
+            ),
+            |(variable, labels)| SchemaPattern::Node { variable, labels },
+        )(input)
+    }
+
+    fn parse_relationship(input: &'a str) -> IResult<&str, Self> {
+        map(
+            delimited(
+                tuple((tag("("), space0, tag(")"), Line::parse, tag("["), space0)),
+                tuple((token::parse, Relationship::parse_types)),
+                tuple((space0, tag("]"), Line::parse, tag("("), space0, tag(")"))),
+            ),
+            |(variable, labels)| SchemaPattern::Relationship { variable, labels },
+        )(input)
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        alt((Self::parse_node, Self::parse_relationship))(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SchemaProperty<'a> {
+    variable: &'a str,
+    key: &'a str,
+}
+
+impl<'a> SchemaProperty<'a> {
+    pub fn format(&self) -> String {
+        format!("{}.{}", self.variable, self.key)
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((token::parse, space0, tag("."), space0, token::parse)),
+            |(variable, _, _, _, key)| SchemaProperty { variable, key },
+        )(input)
+    }
+
+    fn format_list(properties: &[SchemaProperty]) -> String {
+        properties
+            .iter()
+            .map(|p| p.format())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    // Also tells whether the list was bracketed, so that `ON n.embedding`
+    // is not rewritten as `ON (n.embedding)`.
+    fn parse_list(input: &'a str) -> IResult<&str, (Vec<SchemaProperty<'a>>, bool)> {
+        let list = || separated_list1(tuple((space0, tag(","), space0)), SchemaProperty::parse);
+        alt((
+            map(
+                delimited(tuple((tag("("), space0)), list(), tuple((space0, tag(")")))),
+                |properties| (properties, true),
+            ),
+            map(
+                delimited(tuple((tag("["), space0)), list(), tuple((space0, tag("]")))),
+                |properties| (properties, true),
+            ),
+            map(SchemaProperty::parse, |property| (vec![property], false)),
+        ))(input)
+    }
+}
+
+fn parse_name(input: &str) -> IResult<&str, &str> {
+    verify(token::parse_escaped, |name: &str| {
+        !["FOR", "ON", "IF"]
+            .iter()
+            .any(|k| k.eq_ignore_ascii_case(name))
+    })(input)
+}
+
+fn parse_options<'a>(input: &'a str) -> IResult<&str, Option<Map<'a>>> {
+    optional(preceded(
+        tuple((multispace1, keyword("OPTIONS"), multispace0)),
+        Map::parse,
+    ))(input)
+}
+
+fn with_options(command: String, options: &Option<Map>) -> String {
+    let options = match options {
+        Some(options) => format!("OPTIONS {}", options.format()),
+        None => return command,
+    };
+    let single_line = format!("{} {}", command, options);
+    if single_line.len() <= MAX_LINE_WIDTH {
+        return single_line;
+    }
+    format!("{}\n{}", command, options)
+}
+
+fn name_str(name: Option<&str>) -> String {
+    name.map(|n| format!(" {}", n)).unwrap_or_default()
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CreateIndex<'a> {
+    kind: Option<IndexKind>,
+    name: Option<&'a str>,
+    if_not_exists: bool,
+    pattern: SchemaPattern<'a>,
+    properties: Vec<SchemaProperty<'a>>,
+    bracketed: bool,
+    options: Option<Map<'a>>,
+}
+
+impl<'a> CreateIndex<'a> {
+    fn kind_str(&self) -> String {
+        self.kind
+            .as_ref()
+            .map(|k| format!("{} ", k.format()))
+            .unwrap_or_default()
+    }
+
+    fn properties_str(&self) -> String {
+        let properties = SchemaProperty::format_list(&self.properties);
+        if self.kind == Some(IndexKind::Fulltext) {
+            return format!("EACH [{}]", properties);
+        }
+        if !self.bracketed {
+            return properties;
+        }
+        format!("({})", properties)
+    }
+
+    pub fn format(&self) -> String {
+        with_options(
+            format!(
+                "CREATE {}INDEX{}{} FOR {} ON {}",
+                self.kind_str(),
+                name_str(self.name),
+                if_not_exists_str(self.if_not_exists),
+                self.pattern.format(),
+                self.properties_str()
+            ),
+            &self.options,
+        )
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                keyword("CREATE"),
+                multispace1,
+                optional(tuple((IndexKind::parse, multispace1))),
+                keyword("INDEX"),
+                optional(preceded(multispace1, parse_name)),
+                parse_if_not_exists,
+                tuple((multispace1, keyword("FOR"), multispace0)),
+                SchemaPattern::parse,
+                tuple((multispace0, keyword("ON"), multispace0)),
+                optional(tuple((keyword("EACH"), multispace0))),
+                SchemaProperty::parse_list,
+                parse_options,
+            )),
+            |(
+                _,
+                _,
+                kind,
+                _,
+                name,
+                if_not_exists,
+                _,
+                pattern,
+                _,
+                _,
+                (properties, bracketed),
+                options,
+            )| {
+                CreateIndex {
+                    kind: kind.map(|(kind, _)| kind),
+                    name,
+                    if_not_exists,
+                    pattern,
+                    properties,
+                    bracketed,
+                    options,
+                }
+            },
+        )(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ConstraintKind<'a> {
+    Unique(Option<&'static str>),
+    Key(Option<&'static str>),
+    NotNull,
+    Type {
+        operator: &'static str,
+        tokens: Vec<&'a str>,
+    },
+}
+
+impl<'a> ConstraintKind<'a> {
+    pub fn format(&self) -> String {
+        match self {
+            ConstraintKind::Unique(entity) => format!("IS {}UNIQUE", Self::entity_str(entity)),
+            ConstraintKind::Key(entity) => format!("IS {}KEY", Self::entity_str(entity)),
+            ConstraintKind::NotNull => "IS NOT NULL".to_string(),
+            ConstraintKind::Type { operator, tokens } => {
+                format!("IS {} {}", operator, Self::type_str(tokens))
+            }
+        }
+    }
+
+    fn entity_str(entity: &Option<&str>) -> String {
+        entity.map(|e| format!("{} ", e)).unwrap_or_default()
+    }
+
+    fn parse_entity(input: &str) -> IResult<&str, Option<&'static str>> {
+        optional(terminated(
+            parse_keyword_of(&["NODE", "RELATIONSHIP", "REL"]),
+            multispace1,
+        ))(input)
+    }
+
+    fn type_str(tokens: &[&str]) -> String {
+        let mut result = String::new();
+        for token in tokens {
+            match *token {
+                "<" | ">" => result.push_str(token),
+                "|" => result.push_str(" |"),
+                word => {
+                    if !result.is_empty() && !result.ends_with('<') {
+                        result.push(' ');
+                    }
+                    result.push_str(&word.to_uppercase());
+                }
+            }
+        }
+        result
+    }
+
+    // A type such as `LIST<INTEGER NOT NULL> | STRING` is read token by token
+    // so that it stops before a trailing `OPTIONS` map.
+    fn parse_type(input: &'a str) -> IResult<&str, Vec<&'a str>> {
+        let type_token = alt((
+            tag("<"),
+            tag(">"),
+            tag("|"),
+            verify(token::parse, |word: &str| {
+                !word.eq_ignore_ascii_case("OPTIONS")
+            }),
+        ));
+        many1(preceded(multispace0, type_token))(input)
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        preceded(
+            tuple((keyword("IS"), multispace1)),
+            alt((
+                map(
+                    terminated(Self::parse_entity, keyword("UNIQUE")),
+                    ConstraintKind::Unique,
+                ),
+                map(
+                    terminated(Self::parse_entity, keyword("KEY")),
+                    ConstraintKind::Key,
+                ),
+                map(
+                    tuple((keyword("NOT"), multispace1, keyword("NULL"))),
+                    |_| ConstraintKind::NotNull,
+                ),
+                map(
+                    tuple((
+                        alt((map(tag("::"), |_| "::"), map(keyword("TYPED"), |_| "TYPED"))),
+                        multispace0,
+                        Self::parse_type,
+                    )),
+                    |(operator, _, tokens)| ConstraintKind::Type { operator, tokens },
+                ),
+            )),
+        )(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CreateConstraint<'a> {
+    name: Option<&'a str>,
+    if_not_exists: bool,
+    // `FOR` and `REQUIRE`, or the older `ON` and `ASSERT`.
+    for_keyword: &'static str,
+    require_keyword: &'static str,
+    pattern: SchemaPattern<'a>,
+    properties: Vec<SchemaProperty<'a>>,
+    bracketed: bool,
+    kind: ConstraintKind<'a>,
+    options: Option<Map<'a>>,
+}
+
+impl<'a> CreateConstraint<'a> {
+    fn properties_str(&self) -> String {
+        let properties = SchemaProperty::format_list(&self.properties);
+        if !self.bracketed {
+            return properties;
+        }
+        format!("({})", properties)
+    }
+
+    pub fn format(&self) -> String {
+        with_options(
+            format!(
+                "CREATE CONSTRAINT{}{} {} {} {} {} {}",
+                name_str(self.name),
+                if_not_exists_str(self.if_not_exists),
+                self.for_keyword,
+                self.pattern.format(),
+                self.require_keyword,
+                self.properties_str(),
+                self.kind.format()
+            ),
+            &self.options,
+        )
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                keyword("CREATE"),
+                multispace1,
+                keyword("CONSTRAINT"),
+                optional(preceded(multispace1, parse_name)),
+                parse_if_not_exists,
+                delimited(multispace1, parse_keyword_of(&["FOR", "ON"]), multispace0),
+                SchemaPattern::parse,
+                delimited(
+                    multispace0,
+                    parse_keyword_of(&["REQUIRE", "ASSERT"]),
+                    multispace0,
+                ),
+                SchemaProperty::parse_list,
+                multispace1,
+                ConstraintKind::parse,
+                parse_options,
+            )),
+            |(
+                _,
+                _,
+                _,
+                name,
+                if_not_exists,
+                for_keyword,
+                pattern,
+                require_keyword,
+                (properties, bracketed),
+                _,
+                kind,
+                options,
+            )| {
+                CreateConstraint {
+                    name,
+                    if_not_exists,
+                    for_keyword,
+                    pattern,
+                    require_keyword,
+                    properties,
+                    bracketed,
+                    kind,
+                    options,
+                }
+            },
+        )(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DropSchema<'a> {
+    name: &'a str,
+    if_exists: bool,
+}
+
+impl<'a> DropSchema<'a> {
+    pub fn format(&self, kind: &str) -> String {
+        format!(
+            "DROP {} {}{}",
+            kind,
+            self.name,
+            if_exists_str(self.if_exists)
+        )
+    }
+
+    pub fn parse(kind: &'static str) -> impl FnMut(&'a str) -> IResult<&'a str, Self> {
+        map(
+            tuple((
+                keyword("DROP"),
+                multispace1,
+                keyword(kind),
+                multispace1,
+                parse_name,
+                parse_if_exists,
+            )),
+            |(_, _, _, _, name, if_exists)| DropSchema { name, if_exists },
+        )
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SchemaCommand<'a> {
+    CreateIndex(CreateIndex<'a>),
+    DropIndex(DropSchema<'a>),
+    CreateConstraint(CreateConstraint<'a>),
+    DropConstraint(DropSchema<'a>),
+}
+
+impl<'a> SchemaCommand<'a> {
+    pub fn format(&self) -> String {
+        match self {
+            SchemaCommand::CreateIndex(c) => c.format(),
+            SchemaCommand::DropIndex(d) => d.format("INDEX"),
+            SchemaCommand::CreateConstraint(c) => c.format(),
+            SchemaCommand::DropConstraint(d) => d.format("CONSTRAINT"),
+        }
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        alt((
+            map(CreateIndex::parse, SchemaCommand::CreateIndex),
+            map(DropSchema::parse("INDEX"), SchemaCommand::DropIndex),
+            map(CreateConstraint::parse, SchemaCommand::CreateConstraint),
+            map(
+                DropSchema::parse("CONSTRAINT"),
+                SchemaCommand::DropConstraint,
+            ),
+        ))(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(command: &str) -> String {
+        SchemaCommand::parse(command).unwrap().1.format()
+    }
+
+    #[test]
+    fn format_create_index() {
+        assert_eq!(
+            "CREATE INDEX person_name IF NOT EXISTS FOR (p:Person) ON (p.name)",
+            format("create index person_name if not exists for (p:Person) on (p.name)")
+        );
+        assert_eq!(
+            "CREATE TEXT INDEX FOR ()-[r:KNOWS]-() ON (r.since, r.note)",
+            format("CREATE TEXT INDEX FOR ()-[r:KNOWS]->() ON (r.since,r.note)")
+        );
+        assert_eq!(
+            "CREATE FULLTEXT INDEX titles FOR (n:Movie|Book) ON EACH [n.title, n.description]",
+            format(
+                "CREATE FULLTEXT INDEX titles FOR (n:Movie|Book) ON EACH [n.title,n.description]"
+            )
+        );
+    }
+
+    #[test]
+    fn format_create_index_options() {
+        let expected = "CREATE VECTOR INDEX moviePlots IF NOT EXISTS FOR (m:Movie) ON m.embedding\nOPTIONS {indexConfig: {`vector.dimensions`: 1536, `vector.similarity_function`: 'cosine'}}";
+        let actual = format(
+            "CREATE VECTOR INDEX moviePlots IF NOT EXISTS FOR (m:Movie) ON m.embedding OPTIONS {indexConfig: {`vector.dimensions`: 1536, `vector.similarity_function`: 'cosine'}}",
+        );
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_create_constraint() {
+        assert_eq!(
+            "CREATE CONSTRAINT person_id FOR (p:Person) REQUIRE p.id IS NODE UNIQUE",
+            format("CREATE CONSTRAINT person_id FOR (p:Person) REQUIRE p.id is node unique")
+        );
+        assert_eq!(
+            "CREATE CONSTRAINT FOR (p:Person) REQUIRE (p.first, p.last) IS NODE KEY\nOPTIONS {}",
+            format(
+                "create constraint for (p:Person) require (p.first, p.last) is node key options {}"
+            )
+        );
+        assert_eq!(
+            "CREATE CONSTRAINT ON ()-[r:RATED]-() ASSERT r.stars IS NOT NULL",
+            format("CREATE CONSTRAINT ON ()-[r:RATED]-() ASSERT r.stars IS NOT NULL")
+        );
+        assert_eq!(
+            "CREATE CONSTRAINT FOR (p:Person) REQUIRE p.age IS TYPED INTEGER",
+            format("CREATE CONSTRAINT FOR (p:Person) REQUIRE p.age IS TYPED integer")
+        );
+        assert_eq!(
+            "CREATE CONSTRAINT FOR (p:Person) REQUIRE (p.id) IS UNIQUE",
+            format("CREATE CONSTRAINT FOR (p:Person) REQUIRE (p.id) IS UNIQUE")
+        );
+    }
+
+    #[test]
+    fn format_create_constraint_type_options() {
+        assert_eq!(
+            "CREATE CONSTRAINT FOR (p:Person) REQUIRE p.age IS :: INTEGER OPTIONS {}",
+            format("CREATE CONSTRAINT FOR (p:Person) REQUIRE p.age IS :: INTEGER OPTIONS {}")
+        );
+        assert_eq!(
+            "CREATE CONSTRAINT FOR (p:Person) REQUIRE p.tags IS :: LIST<STRING NOT NULL> | STRING",
+            format("CREATE CONSTRAINT FOR (p:Person) REQUIRE p.tags IS :: list < string not null >|string")
+        );
+    }
+
+    #[test]
+    fn format_legacy_constraint() {
+        assert_eq!(
+            "CREATE CONSTRAINT ON (p:Person) ASSERT (p.a, p.b) IS NODE KEY",
+            format("create constraint on (p:Person) assert (p.a, p.b) is node key")
+        );
+    }
+
+    #[test]
+    fn format_backticked_name() {
+        assert_eq!(
+            "CREATE INDEX `my index` FOR (p:Person) ON (p.name)",
+            format("CREATE INDEX `my index` FOR (p:Person) ON (p.name)")
+        );
+        assert_eq!("DROP INDEX `my index`", format("DROP INDEX `my index`"));
+        assert_eq!(
+            "CREATE CONSTRAINT `person id` FOR (p:Person) REQUIRE p.id IS UNIQUE",
+            format("CREATE CONSTRAINT `person id` FOR (p:Person) REQUIRE p.id IS UNIQUE")
+        );
+    }
+
+    #[test]
+    fn format_drop() {
+        assert_eq!(
+            "DROP INDEX person_name IF EXISTS",
+            format("drop index person_name if exists")
+        );
+        assert_eq!(
+            "DROP CONSTRAINT person_id",
+            format("DROP CONSTRAINT person_id")
+        );
+    }
+}
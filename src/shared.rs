@@ -1,4 +1,16 @@
-use nom::{bytes::complete::tag, IResult, Parser};
+use nom::{
+    bytes::complete::{tag, tag_no_case},
+    character::complete::{multispace0, multispace1, satisfy},
+    combinator::{map, not},
+    error::{Error, ErrorKind},
+    multi::separated_list1,
+    sequence::{terminated, tuple},
+    IResult, Parser,
+};
+
+use crate::token;
+
+pub const MAX_LINE_WIDTH: usize = 80;
 
 pub fn is_alphanumeric(c: char) -> bool {
     nom::character::is_alphanumeric(c as u8)
@@ -28,14 +40,132 @@ where
     }
 }
 
+pub fn keyword<'a>(word: &'static str) -> impl FnMut(&'a str) -> IResult<&'a str, &'a str> {
+    terminated(
+        tag_no_case(word),
+        not(satisfy(|c| is_alphanumeric(c) || c == '_')),
+    )
+}
+
+// Parses one of `words` and returns its canonical spelling.
+pub fn parse_keyword_of<'a>(
+    words: &'static [&'static str],
+) -> impl FnMut(&'a str) -> IResult<&'a str, &'static str> {
+    move |input| {
+        words
+            .iter()
+            .find_map(|word| keyword(word)(input).ok().map(|(rest, _)| (rest, *word)))
+            .ok_or_else(|| nom::Err::Error(Error::new(input, ErrorKind::Tag)))
+    }
+}
+
+pub fn indent(text: &str) -> String {
+    text.lines()
+        .map(|line| format!("  {}", line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// The column reached after printing `text` starting at `column`.
+pub fn column_after(column: usize, text: &str) -> usize {
+    match text.rfind('\n') {
+        Some(newline) => text.len() - newline - 1,
+        None => column + text.len(),
+    }
+}
+
+// Joins formatted items, passing each one the column it starts at.
+pub fn join_at<T>(
+    items: &[T],
+    separator: &str,
+    column: usize,
+    format: impl Fn(&T, usize) -> String,
+) -> String {
+    let mut result = String::new();
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            result.push_str(separator);
+        }
+        result.push_str(&format(item, column_after(column, &result)));
+    }
+    result
+}
+
 pub fn optional_signed(input: &str) -> IResult<&str, Option<&str>> {
     optional(tag("-"))(input)
 }
 
+pub fn parse_names(input: &str) -> IResult<&str, Vec<&str>> {
+    separated_list1(tuple((multispace0, tag(","), multispace0)), token::parse)(input)
+}
+
+pub fn parse_if_exists(input: &str) -> IResult<&str, bool> {
+    map(
+        optional(tuple((
+            multispace1,
+            keyword("IF"),
+            multispace1,
+            keyword("EXISTS"),
+        ))),
+        |if_exists| if_exists.is_some(),
+    )(input)
+}
+
+pub fn parse_if_not_exists(input: &str) -> IResult<&str, bool> {
+    map(
+        optional(tuple((
+            multispace1,
+            keyword("IF"),
+            multispace1,
+            keyword("NOT"),
+            multispace1,
+            keyword("EXISTS"),
+        ))),
+        |if_not_exists| if_not_exists.is_some(),
+    )(input)
+}
+
+pub fn if_exists_str(if_exists: bool) -> &'static str {
+    if if_exists {
+        return " IF EXISTS";
+    }
+    ""
+}
+
+pub fn if_not_exists_str(if_not_exists: bool) -> &'static str {
+    if if_not_exists {
+        return " IF NOT EXISTS";
+    }
+    ""
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn parse_keyword() {
+        let expected = Ok((" data", "with"));
+        let actual = keyword("WITH")("with data");
+        assert!(keyword("WITH")("without data").is_err());
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn join_items_at_column() {
+        let actual = join_at(&["a", "b\nc", "d"], ", ", 10, |item, column| {
+            format!("{}@{}", item, column)
+        });
+        assert_eq!("a@10, b\nc@16, d@6", actual);
+    }
+
+    #[test]
+    fn indent_lines() {
+        let expected = "  a\n  b";
+        let actual = indent("a\nb");
+        assert_eq!(expected, actual);
+    }
+
     #[test]
     fn parse_optional_signed() {
         let expected = Ok(("123", Some("-")));
@@ -0,0 +1,78 @@
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::multispace0,
+    combinator::map,
+    sequence::{delimited, tuple},
+    IResult,
+};
+
+use crate::{format_options::FormatOptions, pattern::Pattern, shared::keyword};
+
+#[derive(Debug, PartialEq)]
+pub struct ShortestPath<'a> {
+    pub all: bool,
+    pub pattern: Pattern<'a>,
+}
+
+impl<'a> ShortestPath<'a> {
+    fn function_str(&self) -> &str {
+        if self.all {
+            return "allShortestPaths";
+        }
+        "shortestPath"
+    }
+
+    pub fn format(&self) -> String {
+        self.format_with(&FormatOptions::default())
+    }
+
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        format!(
+            "{}({})",
+            self.function_str(),
+            self.pattern.format_with(options)
+        )
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                alt((
+                    map(keyword("allShortestPaths"), |_| true),
+                    map(keyword("shortestPath"), |_| false),
+                )),
+                multispace0,
+                delimited(
+                    tuple((tag("("), multispace0)),
+                    Pattern::parse,
+                    tuple((multispace0, tag(")"))),
+                ),
+            )),
+            |(all, _, pattern)| ShortestPath { all, pattern },
+        )(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_shortest_path() {
+        let expected = "shortestPath((a) -[:ROAD*..10]- (b))";
+        let actual = ShortestPath::parse("shortestpath( (a)-[:ROAD*..10]-(b) )")
+            .unwrap()
+            .1
+            .format();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_all_shortest_paths() {
+        let (input, actual) = ShortestPath::parse("allShortestPaths((a)--(b)) data").unwrap();
+        assert_eq!(" data", input);
+        assert!(actual.all);
+        assert_eq!(3, actual.pattern.0.len());
+    }
+}
@@ -1,37 +1,109 @@
-use nom::{character::complete::space1, combinator::map, multi::many0, sequence::tuple, IResult};
+use nom::{
+    branch::alt,
+    character::complete::multispace0,
+    combinator::{map, verify},
+    multi::many0,
+    sequence::tuple,
+    IResult,
+};
 
 use crate::{
-    clause::{in_query_call::InQueryCall, r#match::Match, unwind::Unwind},
+    clause::{
+        create::Create, delete::Delete, foreach::Foreach, in_query_call::InQueryCall,
+        load_csv::LoadCsv, merge::Merge, r#match::Match, remove::Remove, set::Set,
+        subquery_call::SubqueryCall, unwind::Unwind,
+    },
+    format_options::FormatOptions,
     r#return::Return,
+    shared::optional,
 };
 
 #[derive(Debug, PartialEq)]
 pub enum ReadingClause<'a> {
     Match(Match<'a>),
     Unwind(Unwind<'a>),
-    InQueryCall(InQueryCall),
+    InQueryCall(InQueryCall<'a>),
+    LoadCsv(LoadCsv<'a>),
+    SubqueryCall(SubqueryCall<'a>),
 }
 
 impl<'a> ReadingClause<'a> {
-    pub fn format(&self) -> String {
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        match self {
+            ReadingClause::Match(m) => m.format_with(options),
+            ReadingClause::Unwind(u) => u.format(),
+            ReadingClause::InQueryCall(c) => c.format(),
+            ReadingClause::LoadCsv(l) => l.format(),
+            ReadingClause::SubqueryCall(s) => s.format_with(options),
+        }
+    }
+
+    pub fn format_inline(&self, options: &FormatOptions) -> Option<String> {
         match self {
-            ReadingClause::Match(m) => m.format(),
-            _ => "".to_string(),
+            ReadingClause::Match(m) => Some(m.format_inline(options)),
+            clause => Some(clause.format_with(options)).filter(|c| !c.contains('\n')),
         }
     }
 
+    fn parse_without_subquery(input: &'a str) -> IResult<&str, Self> {
+        alt((
+            map(Match::parse, ReadingClause::Match),
+            map(Unwind::parse, ReadingClause::Unwind),
+            map(InQueryCall::parse, ReadingClause::InQueryCall),
+            map(LoadCsv::parse, ReadingClause::LoadCsv),
+        ))(input)
+    }
+
     pub fn parse(input: &'a str) -> IResult<&str, Self> {
-        map(Match::parse, ReadingClause::Match)(input)
+        alt((
+            Self::parse_without_subquery,
+            map(SubqueryCall::parse, ReadingClause::SubqueryCall),
+        ))(input)
     }
 }
 
 #[derive(Debug, PartialEq)]
-pub enum UpdatingClause {
-    Create,
-    Merge,
-    Delete,
-    Set,
-    Remove,
+pub enum UpdatingClause<'a> {
+    Create(Create<'a>),
+    Merge(Merge<'a>),
+    Delete(Delete<'a>),
+    Set(Set<'a>),
+    Remove(Remove<'a>),
+    Foreach(Foreach<'a>),
+    SubqueryCall(SubqueryCall<'a>),
+}
+
+impl<'a> UpdatingClause<'a> {
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        match self {
+            UpdatingClause::Create(c) => c.format_with(options),
+            UpdatingClause::Merge(m) => m.format_with(options),
+            UpdatingClause::Delete(d) => d.format(),
+            UpdatingClause::Set(s) => s.format(),
+            UpdatingClause::Remove(r) => r.format(),
+            UpdatingClause::Foreach(f) => f.format_with(options),
+            UpdatingClause::SubqueryCall(s) => s.format_with(options),
+        }
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        alt((
+            map(Create::parse, UpdatingClause::Create),
+            map(Merge::parse, UpdatingClause::Merge),
+            map(Delete::parse, UpdatingClause::Delete),
+            map(Set::parse, UpdatingClause::Set),
+            map(Remove::parse, UpdatingClause::Remove),
+            map(Foreach::parse, UpdatingClause::Foreach),
+            map(SubqueryCall::parse, UpdatingClause::SubqueryCall),
+        ))(input)
+    }
+}
+
+pub fn parse_updating_parts<'a>(input: &'a str) -> IResult<&str, Vec<UpdatingClause<'a>>> {
+    many0(map(
+        tuple((UpdatingClause::parse, multispace0)),
+        |(result, _)| result,
+    ))(input)
 }
 
 #[derive(Debug, PartialEq)]
@@ -41,58 +113,99 @@ pub enum ReturnOrMutate<'a> {
 }
 
 impl<'a> ReturnOrMutate<'a> {
-    pub fn format(&self) -> String {
+    pub fn format_with(&self, options: &FormatOptions) -> String {
         match self {
-            ReturnOrMutate::Return(r) => r.format(),
-            _ => "".to_string(),
+            ReturnOrMutate::Return(r) => r.format_with(options),
+            ReturnOrMutate::Mutate(m) => m.format_with(options),
         }
     }
 
     pub fn parse(input: &'a str) -> IResult<&str, Self> {
-        map(Return::parse, ReturnOrMutate::Return)(input)
+        alt((
+            map(Return::parse, ReturnOrMutate::Return),
+            map(MutationPart::parse, ReturnOrMutate::Mutate),
+        ))(input)
     }
 }
 
 #[derive(Debug, PartialEq)]
 pub struct MutationPart<'a> {
-    mutation_parts: Vec<UpdatingClause>,
+    mutation_parts: Vec<UpdatingClause<'a>>,
     r#return: Option<Return<'a>>,
 }
 
+impl<'a> MutationPart<'a> {
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        self.mutation_parts
+            .iter()
+            .map(|m| m.format_with(options))
+            .chain(self.r#return.iter().map(|r| r.format_with(options)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                verify(parse_updating_parts, |parts: &[_]| !parts.is_empty()),
+                optional(Return::parse),
+            )),
+            |(mutation_parts, r#return)| MutationPart {
+                mutation_parts,
+                r#return,
+            },
+        )(input)
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub struct SinglePartQuery<'a> {
     read_parts: Vec<ReadingClause<'a>>,
     return_or_mutate: ReturnOrMutate<'a>,
 }
 
-fn parse_read_parts<'a>(input: &'a str) -> IResult<&str, Vec<ReadingClause<'a>>> {
-    many0(map(tuple((ReadingClause::parse, space1)), |(result, _)| {
-        result
-    }))(input)
+pub fn parse_read_parts<'a>(input: &'a str) -> IResult<&str, Vec<ReadingClause<'a>>> {
+    many0(map(
+        tuple((ReadingClause::parse, multispace0)),
+        |(result, _)| result,
+    ))(input)
+}
+
+fn parse_read_parts_without_subquery<'a>(input: &'a str) -> IResult<&str, Vec<ReadingClause<'a>>> {
+    many0(map(
+        tuple((ReadingClause::parse_without_subquery, multispace0)),
+        |(result, _)| result,
+    ))(input)
 }
 
 impl<'a> SinglePartQuery<'a> {
-    pub fn format(&self) -> String {
-        let read_parts = self
-            .read_parts
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        self.read_parts
             .iter()
-            .map(|r| r.format())
+            .map(|r| r.format_with(options))
+            .chain(std::iter::once(self.return_or_mutate.format_with(options)))
             .collect::<Vec<_>>()
-            .join("\n");
-        let return_or_mutate = self.return_or_mutate.format();
-        format!("{}\n{}\n", read_parts, return_or_mutate)
+            .join("\n")
     }
 
-    pub fn parse(input: &'a str) -> IResult<&str, Self> {
-        let (input, read_parts) = parse_read_parts(input)?;
-        let (input, return_or_mutate) = ReturnOrMutate::parse(input)?;
-
-        Ok((
-            input,
-            SinglePartQuery {
+    fn parse_parts(
+        read_parts: fn(&'a str) -> IResult<&str, Vec<ReadingClause<'a>>>,
+    ) -> impl FnMut(&'a str) -> IResult<&str, Self> {
+        map(
+            tuple((read_parts, ReturnOrMutate::parse)),
+            |(read_parts, return_or_mutate)| SinglePartQuery {
                 read_parts,
                 return_or_mutate,
             },
-        ))
+        )
+    }
+
+    // `CALL { ... }` is read as a reading clause so that more reading clauses
+    // can follow it, and as an updating clause when it ends the query.
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        alt((
+            Self::parse_parts(parse_read_parts),
+            Self::parse_parts(parse_read_parts_without_subquery),
+        ))(input)
     }
 }
@@ -0,0 +1,154 @@
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{multispace0, multispace1, space0},
+    combinator::map,
+    multi::many0,
+    sequence::{delimited, preceded, tuple},
+    IResult,
+};
+
+use crate::{
+    label::Label,
+    schema_command::IndexKind,
+    shared::{keyword, optional, parse_names},
+    token,
+};
+
+#[derive(Debug, PartialEq)]
+pub enum Hint<'a> {
+    Index {
+        kind: Option<IndexKind>,
+        seek: bool,
+        variable: &'a str,
+        label: Label<'a>,
+        properties: Vec<&'a str>,
+    },
+    Scan {
+        variable: &'a str,
+        label: Label<'a>,
+    },
+    Join {
+        variables: Vec<&'a str>,
+    },
+}
+
+impl<'a> Hint<'a> {
+    pub fn format(&self) -> String {
+        match self {
+            Hint::Index {
+                kind,
+                seek,
+                variable,
+                label,
+                properties,
+            } => format!(
+                "USING {}INDEX {}{}:{}({})",
+                kind.as_ref()
+                    .map(|k| format!("{} ", k.format()))
+                    .unwrap_or_default(),
+                if *seek { "SEEK " } else { "" },
+                variable,
+                label.format(),
+                properties.join(", ")
+            ),
+            Hint::Scan { variable, label } => {
+                format!("USING SCAN {}:{}", variable, label.format())
+            }
+            Hint::Join { variables } => format!("USING JOIN ON {}", variables.join(", ")),
+        }
+    }
+
+    fn parse_index(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                optional(tuple((IndexKind::parse, multispace1))),
+                keyword("INDEX"),
+                multispace1,
+                optional(tuple((keyword("SEEK"), multispace1))),
+                token::parse,
+                Label::parse,
+                delimited(
+                    tuple((space0, tag("("), space0)),
+                    parse_names,
+                    tuple((space0, tag(")"))),
+                ),
+            )),
+            |(kind, _, _, seek, variable, label, properties)| Hint::Index {
+                kind: kind.map(|(kind, _)| kind),
+                seek: seek.is_some(),
+                variable,
+                label,
+                properties,
+            },
+        )(input)
+    }
+
+    fn parse_scan(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((keyword("SCAN"), multispace1, token::parse, Label::parse)),
+            |(_, _, variable, label)| Hint::Scan { variable, label },
+        )(input)
+    }
+
+    fn parse_join(input: &'a str) -> IResult<&str, Self> {
+        map(
+            preceded(
+                tuple((keyword("JOIN"), multispace1, keyword("ON"), multispace1)),
+                parse_names,
+            ),
+            |variables| Hint::Join { variables },
+        )(input)
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        preceded(
+            tuple((keyword("USING"), multispace1)),
+            alt((Self::parse_index, Self::parse_scan, Self::parse_join)),
+        )(input)
+    }
+
+    pub fn parse_many(input: &'a str) -> IResult<&str, Vec<Hint<'a>>> {
+        many0(preceded(multispace0, Self::parse))(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_hint_index() {
+        let expected = Ok((
+            " data",
+            Hint::Index {
+                kind: Some(IndexKind::Text),
+                seek: false,
+                variable: "p",
+                label: Label("Person"),
+                properties: vec!["name"],
+            },
+        ));
+        let actual = Hint::parse("using text index p:Person(name) data");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_hints() {
+        assert_eq!(
+            "USING INDEX SEEK p:Person(first, last)",
+            Hint::parse("USING INDEX SEEK p :Person( first,last )")
+                .unwrap()
+                .1
+                .format()
+        );
+        assert_eq!(
+            "USING SCAN m:Movie",
+            Hint::parse("USING SCAN m:Movie").unwrap().1.format()
+        );
+        assert_eq!(
+            "USING JOIN ON a, b",
+            Hint::parse("using join on a,b").unwrap().1.format()
+        );
+    }
+}
@@ -1 +1,3 @@
+pub mod hint;
 pub mod r#where;
+pub mod r#yield;
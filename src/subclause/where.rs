@@ -1,24 +1,29 @@
-use nom::{bytes::complete::tag, character::complete::space1, IResult};
+use nom::{character::complete::multispace1, IResult};
 
-use crate::expression::Expression;
+use crate::{
+    expression::Expression,
+    format_options::FormatOptions,
+    shared::{join_at, keyword},
+};
 
 #[derive(Debug, PartialEq)]
 pub struct Where<'a>(Vec<Expression<'a>>);
 
 impl<'a> Where<'a> {
     pub fn format(&self) -> String {
+        self.format_with(&FormatOptions::default())
+    }
+
+    pub fn format_with(&self, options: &FormatOptions) -> String {
         format!(
-            "{}",
-            self.0
-                .iter()
-                .map(|e| e.format())
-                .collect::<Vec<_>>()
-                .join(" ")
+            "WHERE {}",
+            join_at(&self.0, " ", "WHERE ".len(), |e, column| e
+                .format_at(options, column))
         )
     }
     pub fn parse(input: &'a str) -> IResult<&str, Self> {
-        let (input, _) = tag("WHERE")(input)?;
-        let (input, _) = space1(input)?;
+        let (input, _) = keyword("WHERE")(input)?;
+        let (input, _) = multispace1(input)?;
         let (input, expression) = Expression::parse(input)?;
         Ok((input, Where(vec![expression])))
     }
@@ -37,6 +42,16 @@ mod tests {
 
     use super::Where;
 
+    #[test]
+    fn format_where() {
+        let expected = "WHERE n.age >= 18 AND n.name IS NOT NULL";
+        let actual = Where::parse("WHERE n.age>=18 and n.name is not null")
+            .unwrap()
+            .1
+            .format();
+        assert_eq!(expected, actual);
+    }
+
     #[test]
     fn parse_where() {
         let expected = Ok((
@@ -0,0 +1,140 @@
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{multispace0, multispace1, space0},
+    combinator::map,
+    multi::separated_list1,
+    sequence::{preceded, terminated, tuple},
+    IResult,
+};
+
+use crate::{
+    shared::{keyword, optional},
+    subclause::r#where::Where,
+    symbolic_name, token,
+};
+
+#[derive(Debug, PartialEq)]
+pub struct YieldItem<'a> {
+    field: Option<&'a str>,
+    variable: &'a str,
+}
+
+impl<'a> YieldItem<'a> {
+    pub fn format(&self) -> String {
+        match self.field {
+            Some(field) => format!("{} AS {}", field, self.variable),
+            None => self.variable.to_string(),
+        }
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                optional(terminated(
+                    token::parse,
+                    tuple((multispace1, keyword("AS"), multispace1)),
+                )),
+                symbolic_name::parse,
+            )),
+            |(field, variable)| YieldItem { field, variable },
+        )(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Yield<'a> {
+    wild_card: bool,
+    items: Vec<YieldItem<'a>>,
+    r#where: Option<Where<'a>>,
+}
+
+impl<'a> Yield<'a> {
+    fn items_str(&self) -> String {
+        if self.wild_card {
+            return "*".to_string();
+        }
+
+        self.items
+            .iter()
+            .map(|i| i.format())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn where_str(&self) -> String {
+        self.r#where
+            .as_ref()
+            .map(|w| format!("\n{}", w.format()))
+            .unwrap_or_default()
+    }
+
+    pub fn format(&self) -> String {
+        format!("YIELD {}{}", self.items_str(), self.where_str())
+    }
+
+    fn parse_wild_card(input: &'a str) -> IResult<&str, Self> {
+        map(tag("*"), |_| Yield {
+            wild_card: true,
+            items: vec![],
+            r#where: None,
+        })(input)
+    }
+
+    fn parse_items(input: &'a str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                separated_list1(tuple((space0, tag(","), multispace0)), YieldItem::parse),
+                optional(preceded(multispace0, Where::parse)),
+            )),
+            |(items, r#where)| Yield {
+                wild_card: false,
+                items,
+                r#where,
+            },
+        )(input)
+    }
+
+    pub fn parse(input: &'a str) -> IResult<&str, Self> {
+        let (input, _) = tuple((keyword("YIELD"), multispace1))(input)?;
+        alt((Self::parse_wild_card, Self::parse_items))(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_yield() {
+        let expected = Ok((
+            " data",
+            Yield {
+                wild_card: false,
+                items: vec![
+                    YieldItem {
+                        field: Some("node"),
+                        variable: "n",
+                    },
+                    YieldItem {
+                        field: None,
+                        variable: "score",
+                    },
+                ],
+                r#where: None,
+            },
+        ));
+        let actual = Yield::parse("yield node as n,score data");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn format_yield_where() {
+        let expected = "YIELD node AS n, score\nWHERE score > 1";
+        let actual = Yield::parse("YIELD node AS n, score WHERE score > 1")
+            .unwrap()
+            .1
+            .format();
+        assert_eq!(expected, actual);
+    }
+}
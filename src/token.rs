@@ -1,7 +1,8 @@
 use nom::{
-    bytes::complete::{take_while, take_while_m_n},
+    branch::alt,
+    bytes::complete::{is_not, tag, take_while, take_while_m_n},
     combinator::recognize,
-    sequence::tuple,
+    sequence::{delimited, tuple},
     IResult,
 };
 
@@ -19,6 +20,11 @@ pub fn parse(input: &str) -> IResult<&str, &str> {
     recognize(tuple((parse_first_character, parse_rest)))(input)
 }
 
+// A name that may also be written in backticks, such as `vector.dimensions`.
+pub fn parse_escaped(input: &str) -> IResult<&str, &str> {
+    alt((parse, recognize(delimited(tag("`"), is_not("`"), tag("`")))))(input)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -29,4 +35,13 @@ mod tests {
         let actual = parse("some_Key_1 data");
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn parse_escaped_key() {
+        assert_eq!(Ok((" data", "some_key")), parse_escaped("some_key data"));
+        assert_eq!(
+            Ok((" data", "`vector.dimensions`")),
+            parse_escaped("`vector.dimensions` data")
+        );
+    }
 }